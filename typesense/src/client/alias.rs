@@ -2,7 +2,11 @@
 //!
 //! An `Alias` instance is created via the main `client.alias("alias_name")` method.
 
-use crate::{Client, Error, execute_wrapper};
+use crate::{
+    Client, Error, ReindexError, execute_wrapper,
+    models::{ImportTally, ReindexOptions, ReindexReport, ReindexSource},
+};
+use futures::{Stream, StreamExt, TryStreamExt, stream::TryChunksError};
 use typesense_codegen::{apis::collections_api, models};
 
 /// Provides methods for interacting with a specific Typesense collection alias.
@@ -40,4 +44,192 @@ impl<'a> Alias<'a> {
         };
         execute_wrapper!(self, collections_api::delete_alias, params)
     }
+
+    /// Re-indexes the documents behind this alias into a new collection, then swaps the alias.
+    ///
+    /// 1. The collection described by `schema` (e.g. `products_v2`) is created.
+    /// 2. The documents from `source` are imported into it.
+    /// 3. Its document count is validated against the number of source documents. On a
+    ///    mismatch, the new collection is deleted again and the alias is left untouched.
+    /// 4. The alias is pointed to the new collection.
+    /// 5. Unless `keep_old_collection` is set, the collection the alias pointed to before is deleted.
+    ///
+    /// The alias is created if it does not exist yet, unless the source is `ReindexSource::Export`,
+    /// which needs an existing collection to export from.
+    ///
+    /// # Arguments
+    /// * `schema` - The schema of the new collection, including its new name.
+    /// * `source` - Where to take the documents from: the current collection's export, or a stream of JSONL chunks.
+    /// * `options` - Whether to keep the old collection, and how to import the documents.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use typesense::{Client, models::{CollectionSchema, Field, ReindexOptions, ReindexSource}};
+    /// # use reqwest::Url;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::builder()
+    /// #    .nodes(vec![Url::parse("http://localhost:8108").unwrap()])
+    /// #    .api_key("xyz")
+    /// #    .build()
+    /// #    .unwrap();
+    /// let schema = CollectionSchema::builder()
+    ///     .name("products_v2")
+    ///     .fields(vec![Field::builder().name("title").r#type("string").build()])
+    ///     .build();
+    /// let options = ReindexOptions::builder().keep_old_collection(true).build();
+    ///
+    /// let report = client
+    ///     .alias("products")
+    ///     .reindex(schema, ReindexSource::export(), options)
+    ///     .await?;
+    /// println!("Reindexed {} documents into {}", report.documents, report.new_collection);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn reindex<S>(
+        &self,
+        schema: models::CollectionSchema<'_>,
+        source: ReindexSource<'_, S>,
+        options: ReindexOptions,
+    ) -> Result<ReindexReport, ReindexError>
+    where
+        S: Stream<Item = String>,
+    {
+        let old_collection = match self.retrieve().await {
+            Ok(alias) => Some(alias.collection_name),
+            Err(err) if err.status() == Some(reqwest::StatusCode::NOT_FOUND) => None,
+            Err(err) => return Err(ReindexError::RetrieveAlias(err)),
+        };
+
+        let new_collection = self
+            .client
+            .collections()
+            .create(schema)
+            .await
+            .map_err(ReindexError::CreateCollection)?
+            .name;
+
+        let (tally, indexed) = match self
+            .import_source(&old_collection, &new_collection, source, &options)
+            .await
+        {
+            Ok(counts) => counts,
+            Err(err) => {
+                let _ = self
+                    .client
+                    .collection_schemaless(&new_collection)
+                    .delete()
+                    .await;
+                return Err(err);
+            }
+        };
+
+        if tally.failed > 0 || indexed != tally.submitted {
+            let _ = self
+                .client
+                .collection_schemaless(&new_collection)
+                .delete()
+                .await;
+            return Err(ReindexError::CountMismatch {
+                expected: tally.submitted,
+                indexed,
+                failed: tally.failed,
+                first_error: tally.first_error,
+            });
+        }
+
+        self.client
+            .aliases()
+            .upsert(
+                self.alias_name,
+                models::CollectionAliasSchema::new(new_collection.as_str().into()),
+            )
+            .await
+            .map_err(ReindexError::UpsertAlias)?;
+
+        let mut old_collection_deleted = false;
+        if let Some(old) = &old_collection
+            && !options.keep_old_collection
+            && *old != new_collection
+        {
+            self.client
+                .collection_schemaless(old)
+                .delete()
+                .await
+                .map_err(ReindexError::DeleteOldCollection)?;
+            old_collection_deleted = true;
+        }
+
+        Ok(ReindexReport {
+            old_collection,
+            new_collection,
+            documents: indexed,
+            old_collection_deleted,
+        })
+    }
+
+    /// Imports the documents of `source` into `new_collection`.
+    ///
+    /// Returns the counts of the import responses, and the number of documents in the new collection.
+    async fn import_source<S>(
+        &self,
+        old_collection: &Option<String>,
+        new_collection: &str,
+        source: ReindexSource<'_, S>,
+        options: &ReindexOptions,
+    ) -> Result<(ImportTally, u64), ReindexError>
+    where
+        S: Stream<Item = String>,
+    {
+        let collection = self.client.collection_schemaless(new_collection);
+        let documents = collection.documents();
+        let mut tally = ImportTally::default();
+
+        let mut import = async |jsonl: String| -> Result<(), ReindexError> {
+            let response = documents
+                .import_jsonl(jsonl, options.import_parameters.clone())
+                .await
+                .map_err(ReindexError::Import)?;
+            tally
+                .record(&response)
+                .map_err(|err| ReindexError::Import(Error::from(err)))
+        };
+
+        match source {
+            ReindexSource::Export(params) => {
+                let Some(old_collection) = old_collection else {
+                    return Err(ReindexError::AliasNotFound(self.alias_name.to_owned()));
+                };
+                let lines = self
+                    .client
+                    .collection_schemaless(old_collection)
+                    .documents()
+                    .export_jsonl_lines(params)
+                    .await
+                    .map_err(ReindexError::Export)?
+                    .try_chunks(options.batch_size.max(1))
+                    .map_err(|TryChunksError(_, err)| ReindexError::Export(err));
+                let mut lines = std::pin::pin!(lines);
+                while let Some(chunk) = lines.try_next().await? {
+                    import(chunk.join("\n")).await?;
+                }
+            }
+            ReindexSource::Stream(stream) => {
+                let mut stream = std::pin::pin!(stream);
+                while let Some(jsonl) = stream.next().await {
+                    if !jsonl.trim().is_empty() {
+                        import(jsonl).await?;
+                    }
+                }
+            }
+        }
+
+        let indexed = collection
+            .retrieve()
+            .await
+            .map_err(ReindexError::RetrieveCollection)?
+            .num_documents
+            .max(0) as u64;
+        Ok((tally, indexed))
+    }
 }
@@ -4,7 +4,7 @@
 //! via a parent `Collection` struct, for example:
 //! `client.collection::<Book>().document("123")`
 
-use crate::{Client, Error, execute_wrapper, traits};
+use crate::{Client, Error, execute_wrapper, models::DocumentRetrieveParameters, traits};
 use ::std::borrow::Cow;
 use serde::de::DeserializeOwned;
 use typesense_codegen::apis::documents_api;
@@ -50,14 +50,59 @@ where
     /// # Returns
     /// A `Result` containing the strongly-typed document `D` if successful.
     pub async fn retrieve(&self) -> Result<D, Error<documents_api::GetDocumentError>> {
+        self.retrieve_with(DocumentRetrieveParameters::default())
+            .await
+    }
+
+    /// Fetches a projection of this document, restricted by `include_fields` and `exclude_fields`,
+    /// and deserializes it into a caller-chosen type `P`.
+    ///
+    /// Since the server omits the excluded fields, `P` usually only declares the included ones,
+    /// or makes the rest optional.
+    ///
+    /// # Arguments
+    /// * `params` - A `DocumentRetrieveParameters` struct listing the fields to include or exclude.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use serde::Deserialize;
+    /// # use typesense::{Client, models};
+    /// # use reqwest::Url;
+    /// #[derive(Deserialize)]
+    /// struct BookTitle {
+    ///     title: String,
+    /// }
+    ///
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::builder()
+    /// #    .nodes(vec![Url::parse("http://localhost:8108").unwrap()])
+    /// #    .api_key("xyz")
+    /// #    .build()
+    /// #    .unwrap();
+    /// let params = models::DocumentRetrieveParameters::builder()
+    ///     .include_fields("title")
+    ///     .build();
+    /// let book: BookTitle = client.collection_schemaless("books").document("123")
+    ///     .retrieve_with(params)
+    ///     .await?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn retrieve_with<P: DeserializeOwned>(
+        &self,
+        params: DocumentRetrieveParameters<'_>,
+    ) -> Result<P, Error<documents_api::GetDocumentError>> {
         let params = documents_api::GetDocumentParams {
             collection_name: self.collection_name.into(),
             document_id: self.document_id.as_ref().into(),
+            include_fields: params.include_fields,
+            exclude_fields: params.exclude_fields,
         };
 
         let result_value = execute_wrapper!(self, documents_api::get_document, params)?;
 
-        // Deserialize the raw JSON value into the user's type D.
+        // Deserialize the raw JSON value into the requested type.
         serde_json::from_value(result_value).map_err(Error::from)
     }
 
@@ -83,6 +128,44 @@ impl<'d, D> Document<'d, D>
 where
     D: traits::Document,
 {
+    /// Fetches a projection of this document, restricted by `include_fields` and `exclude_fields`,
+    /// and deserializes it into `D::Partial`, whose fields are all optional.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use serde::{Serialize, Deserialize};
+    /// # use typesense::{Client, Typesense, models};
+    /// # use reqwest::Url;
+    /// # #[derive(Typesense, Serialize, Deserialize)]
+    /// # struct Book { id: String, title: String, embedding: Vec<f32> }
+    /// #
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::builder()
+    /// #    .nodes(vec![Url::parse("http://localhost:8108").unwrap()])
+    /// #    .api_key("xyz")
+    /// #    .build()
+    /// #    .unwrap();
+    /// let params = models::DocumentRetrieveParameters::builder()
+    ///     .exclude_fields("embedding")
+    ///     .build();
+    /// let book: BookPartial = client.collection_named::<Book>("books").document("123")
+    ///     .retrieve_partial(params)
+    ///     .await?;
+    /// assert!(book.embedding.is_none());
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn retrieve_partial(
+        &self,
+        params: DocumentRetrieveParameters<'_>,
+    ) -> Result<D::Partial, Error<documents_api::GetDocumentError>>
+    where
+        D::Partial: DeserializeOwned,
+    {
+        self.retrieve_with(params).await
+    }
+
     /// Updates this individual document. The update can be partial.
     /// The updated full document is returned.
     ///
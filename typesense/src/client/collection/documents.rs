@@ -5,17 +5,36 @@
 //! `client.collection_named::<T>("...").documents()`.
 
 use crate::{
-    Client, Error, execute_wrapper,
-    models::{DocumentIndexParameters, SearchResult},
+    ApiError, Client, Error, ImportRetryError, ResponseContent, ThrottledImportError,
+    execute_wrapper,
+    models::{
+        AdaptiveThrottle, DocumentIndexParameters, FailedImportLine, Filter, GetManyResult,
+        ImportLineErrorKind, ImportLineResponse, ImportReport, ImportRetryOptions,
+        ImportThrottleOptions, KeysetDocument, MultiSearchBody, ScrollOptions, SearchPagesOptions,
+        SearchResult, SearchResultHit, ThrottleSignal, ThrottledImportReport,
+    },
     traits,
 };
-use ::std::borrow::Cow;
-use serde::de::DeserializeOwned;
+#[cfg(feature = "csv")]
+use crate::{
+    CsvImportError,
+    models::{CsvDocuments, CsvImportBatch, CsvImportOptions, CsvImportReport},
+};
+use ::std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+};
+use futures::{
+    Stream, StreamExt, TryStreamExt,
+    future::{self, Either},
+    stream,
+};
+use serde::{Serialize, de::DeserializeOwned};
 use typesense_codegen::{
-    apis::documents_api,
+    apis::{self, configuration, documents_api},
     models::{
         self as raw_models, DeleteDocumentsParameters, ExportDocumentsParameters,
-        ImportDocumentsParameters, UpdateDocumentsParameters,
+        ImportDocumentsParameters, IndexAction, UpdateDocumentsParameters,
     },
 };
 /// Provides methods for interacting with documents within a specific Typesense collection.
@@ -46,21 +65,52 @@ where
         }
     }
 
-    /// Indexes a document in the collection.
+    /// Indexes a single document in the collection using the given action.
+    ///
+    /// The raw response is returned, since its shape depends on the action: `update` and `emplace`
+    /// may be sent a partial document and echo back only the fields that were sent.
+    ///
     /// # Arguments
-    /// * `document` - A `serde_json::Value` representing the document.
-    /// * `action` - The indexing action to perform (e.g., "create", "upsert").
-    async fn index(
+    /// * `document` - A serializable struct or a `serde_json::Value` representing the document.
+    /// * `action` - The indexing action to perform: `Create`, `Upsert`, `Update` or `Emplace`.
+    /// * `params` - Optional parameters like `dirty_values`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use typesense::{Client, models};
+    /// # use reqwest::Url;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::builder()
+    /// #    .nodes(vec![Url::parse("http://localhost:8108").unwrap()])
+    /// #    .api_key("xyz")
+    /// #    .build()
+    /// #    .unwrap();
+    /// let params = models::DocumentIndexParameters {
+    ///     dirty_values: Some(models::DirtyValues::CoerceOrDrop),
+    /// };
+    /// client
+    ///     .collection_schemaless("books")
+    ///     .documents()
+    ///     .index(
+    ///         &serde_json::json!({ "id": "123", "pages": "654" }),
+    ///         models::IndexAction::Emplace,
+    ///         Some(params),
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn index(
         &self,
-        document: serde_json::Value,
-        action: &str,
+        document: &impl Serialize,
+        action: IndexAction,
         params: Option<DocumentIndexParameters>,
     ) -> Result<serde_json::Value, Error<documents_api::IndexDocumentError>> {
         let params = documents_api::IndexDocumentParams {
             collection_name: self.collection_name.into(),
-            body: document,
-            action: Some(action.into()),
-            dirty_values: params.and_then(|d| d.dirty_values), // Or expose this as an argument if needed
+            body: serde_json::to_value(document)?,
+            action: Some(action.to_string().into()),
+            dirty_values: params.and_then(|d| d.dirty_values),
         };
         execute_wrapper!(self, documents_api::index_document, params)
     }
@@ -93,6 +143,378 @@ where
         execute_wrapper!(self, documents_api::import_documents, params)
     }
 
+    /// Imports documents from CSV or TSV input.
+    ///
+    /// Every row is converted to a JSON document, with each column converted to the type of the
+    /// collection field of the same name. See `CsvImportOptions` for how cells are converted.
+    /// The rows are converted `options.batch_size` at a time, and every batch is sent in its own
+    /// import request before the next one is converted, so only one batch of JSON documents is
+    /// held in memory at a time.
+    ///
+    /// If a row cannot be converted, or an import request fails, the import stops with an error
+    /// that names the line of the row, or the lines of the batch. The batches before it have
+    /// been imported, and are listed in the report of the error.
+    ///
+    /// The documents are not checked locally. To do so, convert them with
+    /// `CsvImportOptions::to_jsonl` and check them with `DocumentValidator::validate_jsonl`.
+    ///
+    /// # Arguments
+    /// * `input` - The CSV input, starting with a header row. It is taken from memory, so that
+    ///   converting the rows never blocks on I/O between the requests.
+    /// * `options` - A `CsvImportOptions` struct with the delimiters, geopoint columns, batch size and import parameters.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use typesense::{Client, models::CsvImportOptions};
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::builder()
+    /// #    .nodes(vec!["http://localhost:8108"])
+    /// #    .api_key("xyz")
+    /// #    .build()
+    /// #    .unwrap();
+    /// let input = std::fs::read("books.tsv")?;
+    /// let report = client
+    ///     .collection_schemaless("books")
+    ///     .documents()
+    ///     .import_csv(&input, CsvImportOptions::tsv())
+    ///     .await?;
+    /// println!("Imported {} rows in {} batches", report.rows(), report.batches.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "csv")]
+    pub async fn import_csv(
+        &self,
+        input: &[u8],
+        options: CsvImportOptions,
+    ) -> Result<CsvImportReport, CsvImportError> {
+        let fields = match &options.fields {
+            Some(fields) => fields.clone(),
+            None => {
+                self.client
+                    .collection_schemaless(self.collection_name)
+                    .retrieve()
+                    .await
+                    .map_err(CsvImportError::Retrieve)?
+                    .fields
+            }
+        };
+
+        let mut rows = CsvDocuments::new(&options, input, &fields)?;
+        let mut report = CsvImportReport::default();
+        loop {
+            let mut lines = Vec::with_capacity(options.batch_size.max(1));
+            let mut first_row = None;
+            let mut last_row = 0;
+            while lines.len() < options.batch_size.max(1)
+                && let Some((row, document)) = rows
+                    .next_document()
+                    .map_err(|err| err.with_report(std::mem::take(&mut report)))?
+            {
+                first_row.get_or_insert(row);
+                last_row = row;
+                lines.push(document);
+            }
+            let Some(first_row) = first_row else {
+                return Ok(report);
+            };
+
+            let response = self
+                .import_jsonl(lines.join("\n"), options.import_parameters.clone())
+                .await
+                .map_err(|source| CsvImportError::Import {
+                    rows: first_row..=last_row,
+                    report: std::mem::take(&mut report),
+                    source,
+                })?;
+            report.batches.push(CsvImportBatch {
+                rows: first_row..=last_row,
+                documents: lines.len(),
+                response,
+            });
+        }
+    }
+
+    /// Imports a batch of documents in JSONL format, retrying lines that failed transiently.
+    ///
+    /// Every failed line of the import response is classified with `options.classifier`.
+    /// Retriable lines (e.g. remote embedding timeouts) are re-submitted with exponential
+    /// backoff, up to `options.max_retries` times. Lines that failed permanently, or that are
+    /// still failing once the retries are exhausted, are handed to `dead_letters` together
+    /// with their last error message.
+    ///
+    /// A request that fails as a whole with a server error, a timeout, a rate limit or a
+    /// network error is sent again with the same backoff, and counts as an attempt for all of
+    /// its lines. A request that timed out may have been imported in part, so use the `upsert`
+    /// or `emplace` action to make sending it again safe.
+    ///
+    /// When the import stops with an error, the error holds the `ImportReport` of what was
+    /// imported and dead-lettered so far, and the lines that were neither.
+    ///
+    /// # Arguments
+    /// * `documents_jsonl` - A string containing the documents in JSONL format.
+    /// * `params` - An `ImportDocumentsParameters` struct containing options like `action` and `batch_size`.
+    /// * `options` - An `ImportRetryOptions` struct controlling retries, backoff and optional local validation.
+    /// * `dead_letters` - The destination for lines that could not be imported.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use typesense::{Client, models::{ImportDocumentsParameters, ImportRetryOptions}};
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::builder()
+    /// #    .nodes(vec!["http://localhost:8108"])
+    /// #    .api_key("xyz")
+    /// #    .build()
+    /// #    .unwrap();
+    /// # let jsonl = String::new();
+    /// let mut dead_letters = Vec::new();
+    /// let report = client
+    ///     .collection_schemaless("products")
+    ///     .documents()
+    ///     .import_jsonl_with_retry(
+    ///         jsonl,
+    ///         ImportDocumentsParameters::default(),
+    ///         ImportRetryOptions::default(),
+    ///         &mut dead_letters,
+    ///     )
+    ///     .await?;
+    /// println!("{} imported, {} dead-lettered", report.imported, report.dead_lettered);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn import_jsonl_with_retry(
+        &self,
+        documents_jsonl: impl AsRef<str>,
+        params: ImportDocumentsParameters,
+        options: ImportRetryOptions,
+        dead_letters: &mut impl traits::DeadLetterSink,
+    ) -> Result<ImportReport, ImportRetryError> {
+        let mut report = ImportReport::default();
+        let mut pending: Vec<&str> = documents_jsonl
+            .as_ref()
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .collect();
+        let mut attempt = 0;
+        let unfinished = |lines: &[&str]| lines.iter().map(|line| (*line).to_owned()).collect();
+
+        if let Some(validator) = &options.validator {
+            let validation =
+                validator.validate_jsonl(documents_jsonl.as_ref(), params.action.as_ref());
+            for (i, invalid) in validation.invalid.iter().enumerate() {
+                let line = FailedImportLine {
+                    document: invalid.document.to_owned(),
+                    error: invalid
+                        .errors
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>()
+                        .join("; "),
+                    code: None,
+                    kind: ImportLineErrorKind::Permanent,
+                    attempts: 0,
+                };
+                if let Err(source) = dead_letters.send(line) {
+                    let rest = validation.invalid[i..]
+                        .iter()
+                        .map(|invalid| invalid.document);
+                    return Err(ImportRetryError::DeadLetter {
+                        report,
+                        unfinished: rest.chain(validation.valid).map(str::to_owned).collect(),
+                        source,
+                    });
+                }
+                report.dead_lettered += 1;
+            }
+            pending = validation.valid;
+        }
+
+        while !pending.is_empty() {
+            if attempt > 0 {
+                crate::client::sleep(options.backoff(attempt)).await;
+            }
+            attempt += 1;
+
+            report.requests += 1;
+            let response = match self.import_jsonl(pending.join("\n"), params.clone()).await {
+                Ok(response) => response,
+                // The whole request is sent again, like a batch of retriable lines.
+                Err(err) if err.is_transient() && attempt <= options.max_retries => continue,
+                Err(source) => {
+                    return Err(ImportRetryError::Import {
+                        report,
+                        unfinished: unfinished(&pending),
+                        source,
+                    });
+                }
+            };
+
+            let responses = response
+                .lines()
+                .filter(|line| !line.trim().is_empty())
+                .map(serde_json::from_str::<ImportLineResponse>)
+                .collect::<Result<Vec<_>, _>>();
+            let responses = match responses {
+                Ok(responses) if responses.len() == pending.len() => responses,
+                Ok(responses) => {
+                    return Err(ImportRetryError::ResponseMismatch {
+                        expected: pending.len(),
+                        received: responses.len(),
+                        unfinished: unfinished(&pending),
+                        report,
+                    });
+                }
+                Err(err) => {
+                    return Err(ImportRetryError::Import {
+                        report,
+                        unfinished: unfinished(&pending),
+                        source: err.into(),
+                    });
+                }
+            };
+
+            let mut failed = Vec::new();
+            for (line, response) in pending.into_iter().zip(responses) {
+                if response.success {
+                    report.imported += 1;
+                    if attempt > 1 {
+                        report.recovered += 1;
+                    }
+                } else {
+                    failed.push((line, response));
+                }
+            }
+
+            let mut retry = Vec::new();
+            let mut failed = failed.into_iter();
+            while let Some((line, response)) = failed.next() {
+                let error = response.error.unwrap_or_default();
+                let kind = (options.classifier)(response.code, &error);
+                if kind == ImportLineErrorKind::Retriable && attempt <= options.max_retries {
+                    retry.push(line);
+                    continue;
+                }
+
+                let failed_line = FailedImportLine {
+                    document: line.to_owned(),
+                    error,
+                    code: response.code,
+                    kind,
+                    attempts: attempt,
+                };
+                if let Err(source) = dead_letters.send(failed_line) {
+                    retry.push(line);
+                    retry.extend(failed.map(|(line, _)| line));
+                    return Err(ImportRetryError::DeadLetter {
+                        report,
+                        unfinished: unfinished(&retry),
+                        source,
+                    });
+                }
+                report.dead_lettered += 1;
+            }
+            pending = retry;
+        }
+
+        Ok(report)
+    }
+
+    /// Imports a large set of documents in JSONL format, adapting to server backpressure.
+    ///
+    /// The documents are split into batches that are imported concurrently, one round at a
+    /// time. While a round is in flight, the server's API stats are polled with
+    /// `client.operations().retrieve_api_stats()`: while `pending_write_batches` or
+    /// `write_latency_ms` are too high, batch size and concurrency shrink and the next round
+    /// waits for `options.cooldown`. Once the server recovers they grow again.
+    /// See [`AdaptiveThrottle`] for the exact rules. Failing to fetch the stats does not fail
+    /// the import; the current settings are simply kept.
+    ///
+    /// The throttle reacts to the stats of a round only from the next round on, so the
+    /// batches of a round are never resized once sent. Keep `initial_concurrency` low for a
+    /// server that is already close to its capacity.
+    ///
+    /// When an import request fails, the batches of its round still finish, no further rounds
+    /// are started, and the error carries the report of everything that was imported.
+    ///
+    /// The documents are not checked locally. To skip invalid documents before they are sent,
+    /// pass the `valid_jsonl` of `DocumentValidator::validate_jsonl`.
+    ///
+    /// # Arguments
+    /// * `documents_jsonl` - A string containing the documents in JSONL format.
+    /// * `params` - An `ImportDocumentsParameters` struct containing options like `action` and `batch_size`.
+    /// * `options` - An `ImportThrottleOptions` struct with the batch size and concurrency bounds.
+    pub async fn import_jsonl_throttled(
+        &self,
+        documents_jsonl: impl AsRef<str>,
+        params: ImportDocumentsParameters,
+        options: ImportThrottleOptions,
+    ) -> Result<ThrottledImportReport, ThrottledImportError> {
+        let lines: Vec<&str> = documents_jsonl
+            .as_ref()
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .collect();
+        let mut throttle = AdaptiveThrottle::new(options);
+        let mut report = ThrottledImportReport::default();
+        let mut first_error = None;
+
+        while report.submitted_lines < lines.len() {
+            let mut batches = Vec::with_capacity(throttle.concurrency());
+            for _ in 0..throttle.concurrency() {
+                let start = report.submitted_lines;
+                if start == lines.len() {
+                    break;
+                }
+                let end = (start + throttle.batch_size()).min(lines.len());
+                let import = self.import_jsonl(lines[start..end].join("\n"), params.clone());
+                batches.push(async move { (start..end, import.await) });
+                report.submitted_lines = end;
+            }
+
+            let (responses, stats) = future::join(
+                future::join_all(batches),
+                self.client.operations().retrieve_api_stats(),
+            )
+            .await;
+
+            for (lines, response) in responses {
+                report.batches += 1;
+                match response {
+                    Ok(response) => {
+                        if !report.response.is_empty() && !response.is_empty() {
+                            report.response.push('\n');
+                        }
+                        report.response.push_str(response.trim_end());
+                    }
+                    Err(e) => {
+                        report.failed_batches.push(lines);
+                        first_error.get_or_insert(e);
+                    }
+                }
+            }
+
+            if first_error.is_some() || report.submitted_lines == lines.len() {
+                break;
+            }
+            if let Ok(stats) = stats
+                && throttle.observe(&stats) == ThrottleSignal::Backpressure
+            {
+                report.throttled += 1;
+                crate::client::sleep(throttle.options().cooldown).await;
+            }
+        }
+
+        report.final_batch_size = throttle.batch_size();
+        report.final_concurrency = throttle.concurrency();
+        match first_error {
+            Some(source) => Err(ThrottledImportError {
+                report: Box::new(report),
+                source,
+            }),
+            None => Ok(report),
+        }
+    }
+
     /// Exports all documents in a collection in JSONL format.
     ///
     /// # Arguments
@@ -110,6 +532,312 @@ where
         execute_wrapper!(self, documents_api::export_documents, params)
     }
 
+    /// Exports all documents in a collection in JSONL format, as a stream of its lines.
+    ///
+    /// Unlike `export_jsonl`, the response body is read while it arrives, so a large collection
+    /// is never held in memory as a whole. The request is sent, and its status checked, before
+    /// this method returns. Reading the body can still fail while the stream is consumed.
+    ///
+    /// # Arguments
+    /// * `params` - An `ExportDocumentsParameters` struct containing options like `filter_by` and `include_fields`.
+    pub async fn export_jsonl_lines(
+        &self,
+        params: ExportDocumentsParameters<'_>,
+    ) -> Result<
+        impl Stream<Item = Result<String, Error<documents_api::ExportDocumentsError>>> + use<D>,
+        Error<documents_api::ExportDocumentsError>,
+    > {
+        let params = documents_api::ExportDocumentsParams {
+            collection_name: self.collection_name.into(),
+            exclude_fields: params.exclude_fields,
+            filter_by: params.filter_by,
+            include_fields: params.include_fields,
+        };
+        let response = execute_wrapper!(self, export_documents_response, params)?;
+        Ok(jsonl_lines(response.bytes_stream()))
+    }
+
+    /// Fetches many documents by their ids.
+    ///
+    /// The ids are looked up in chunks of 100 through `id:=[...]` filters on the export endpoint,
+    /// with up to 4 chunks requested concurrently. Documents are returned in the order their ids were
+    /// requested, and ids without a matching document are reported in `missing`.
+    ///
+    /// # Arguments
+    /// * `ids` - The ids of the documents to fetch.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use typesense::Client;
+    /// # use reqwest::Url;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::builder()
+    /// #    .nodes(vec![Url::parse("http://localhost:8108").unwrap()])
+    /// #    .api_key("xyz")
+    /// #    .build()
+    /// #    .unwrap();
+    /// let result = client
+    ///     .collection_schemaless("books")
+    ///     .documents()
+    ///     .get_many(["12", "7", "42"])
+    ///     .await?;
+    /// for id in &result.missing {
+    ///     println!("No book with id {id}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_many<I>(
+        &self,
+        ids: I,
+    ) -> Result<GetManyResult<D>, Error<documents_api::ExportDocumentsError>>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        /// The number of ids looked up per export request, keeping the query string short.
+        const CHUNK_SIZE: usize = 100;
+        /// The number of export requests in flight at once.
+        const MAX_CONCURRENT_EXPORTS: usize = 4;
+
+        let mut seen = HashSet::new();
+        let ids: Vec<String> = ids
+            .into_iter()
+            .map(|id| id.as_ref().to_owned())
+            .filter(|id| seen.insert(id.clone()))
+            .collect();
+
+        let mut found = HashMap::with_capacity(ids.len());
+        {
+            let mut exports = stream::iter(ids.chunks(CHUNK_SIZE))
+                .map(|chunk| {
+                    self.export_jsonl(ExportDocumentsParameters {
+                        filter_by: Some(Filter::field("id").one_of(chunk).into()),
+                        ..Default::default()
+                    })
+                })
+                .buffer_unordered(MAX_CONCURRENT_EXPORTS);
+            while let Some(export) = exports.try_next().await? {
+                for line in export.lines().filter(|line| !line.trim().is_empty()) {
+                    let document: serde_json::Value = serde_json::from_str(line)?;
+                    if let Some(id) = document.get("id").and_then(|id| id.as_str()) {
+                        found.insert(id.to_owned(), document);
+                    }
+                }
+            }
+        }
+
+        let mut result = GetManyResult::default();
+        for id in ids {
+            match found.remove(&id) {
+                Some(document) => result.documents.push(serde_json::from_value(document)?),
+                None => result.missing.push(id),
+            }
+        }
+        Ok(result)
+    }
+
+    /// Walks every hit of a search with keyset pagination.
+    ///
+    /// Deep `page` numbers get slow and are capped by the server. Instead, the hits are ordered
+    /// by `options.sort_field` and then `id`, and every request continues after the last hit of
+    /// the previous one: `(field > v) || (field = v && id > last_id)`. The user's `filter_by` is
+    /// kept, while `sort_by`, `page`, `per_page`, `offset` and `limit` are replaced.
+    ///
+    /// The stream ends after the first request that returns fewer than `options.per_page` hits.
+    ///
+    /// # Arguments
+    /// * `params` - The search, e.g. `q`, `query_by` and `filter_by`.
+    /// * `options` - A `ScrollOptions` struct naming the numeric field to paginate by.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use typesense::{Client, models::{ScrollOptions, SearchParameters}};
+    /// # use futures::TryStreamExt;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::builder()
+    /// #    .nodes(vec!["http://localhost:8108"])
+    /// #    .api_key("xyz")
+    /// #    .build()
+    /// #    .unwrap();
+    /// let orders = client.collection_schemaless("orders");
+    /// let documents = orders.documents();
+    /// let params = SearchParameters::builder()
+    ///     .q("*")
+    ///     .filter_by("status:=shipped")
+    ///     .build();
+    /// let hits = documents.scroll(params, ScrollOptions::builder().sort_field("created_at").build());
+    /// futures::pin_mut!(hits);
+    /// while let Some(hit) = hits.try_next().await? {
+    ///     println!("{:?}", hit.document);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn scroll<'s>(
+        &'s self,
+        params: raw_models::SearchParameters<'s>,
+        options: ScrollOptions,
+    ) -> impl Stream<Item = Result<SearchResultHit<D>, Error<documents_api::SearchCollectionError>>> + 's
+    {
+        let (client, collection_name) = (self.client, self.collection_name);
+        let order = if options.descending { "desc" } else { "asc" };
+        let sort_by = format!("{}:{order},id:{order}", options.sort_field);
+
+        // The state is `None` once the last page was fetched. Otherwise it holds the sort value
+        // and id of the last hit, if any page was fetched.
+        let initial: Option<Option<(serde_json::Number, String)>> = Some(None);
+        stream::try_unfold(initial, move |state| {
+            let mut filter = params.filter_by.as_deref().map(Filter::raw);
+            if let Some(Some((value, id))) = &state {
+                let field = || Filter::field(options.sort_field.as_str());
+                let after = if options.descending {
+                    field()
+                        .lt(value)
+                        .or(field().eq(value).and(Filter::field("id").lt(id)))
+                } else {
+                    field()
+                        .gt(value)
+                        .or(field().eq(value).and(Filter::field("id").gt(id)))
+                };
+                filter = Some(match filter {
+                    Some(filter) => filter.and(after),
+                    None => after,
+                });
+            }
+            let params = raw_models::SearchParameters {
+                filter_by: filter.map(Into::into),
+                sort_by: Some(sort_by.clone().into()),
+                page: Some(1),
+                per_page: Some(options.per_page),
+                offset: None,
+                limit: None,
+                ..params.clone()
+            };
+            let (sort_field, per_page) = (options.sort_field.clone(), options.per_page);
+
+            async move {
+                if state.is_none() {
+                    return Ok::<_, Error<documents_api::SearchCollectionError>>(None);
+                }
+                let hits = Documents::<KeysetDocument<D>>::new(client, collection_name)
+                    .search(params)
+                    .await?
+                    .hits
+                    .unwrap_or_default();
+                let Some(last) = hits.last() else {
+                    return Ok(None);
+                };
+
+                let next = if hits.len() >= per_page as usize {
+                    let last = last.document.as_ref().ok_or_else(|| {
+                        <serde_json::Error as serde::de::Error>::custom("hit has no document")
+                    })?;
+                    Some(Some(last.keyset(&sort_field)?))
+                } else {
+                    None
+                };
+                let hits = hits.into_iter().map(KeysetDocument::into_hit).collect();
+                Ok(Some((hits, next)))
+            }
+        })
+        .map_ok(|hits: Vec<SearchResultHit<D>>| stream::iter(hits.into_iter().map(Ok)))
+        .try_flatten()
+    }
+
+    /// Fetches the pages of a search one after the other, as a stream of results.
+    ///
+    /// The stream starts at `params.page` (or the first page) and ends with the last page that
+    /// holds hits, as computed from the `found` count of the first response. For result sets that
+    /// are too deep for `page` numbers, use `scroll`.
+    ///
+    /// # Arguments
+    /// * `params` - The search. Its `per_page` sets the page size.
+    /// * `options` - A `SearchPagesOptions` struct, e.g. to prefetch the next page.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use typesense::{Client, models::{SearchPagesOptions, SearchParameters}};
+    /// # use futures::TryStreamExt;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::builder()
+    /// #    .nodes(vec!["http://localhost:8108"])
+    /// #    .api_key("xyz")
+    /// #    .build()
+    /// #    .unwrap();
+    /// let books = client.collection_schemaless("books");
+    /// let documents = books.documents();
+    /// let params = SearchParameters::builder()
+    ///     .q("dune")
+    ///     .query_by("title")
+    ///     .per_page(100)
+    ///     .build();
+    /// let pages = documents.search_pages(params, SearchPagesOptions::builder().prefetch(true).build());
+    /// futures::pin_mut!(pages);
+    /// while let Some(page) = pages.try_next().await? {
+    ///     println!("page {:?}: {} hits", page.page, page.hits.unwrap_or_default().len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn search_pages<'s>(
+        &'s self,
+        params: raw_models::SearchParameters<'s>,
+        options: SearchPagesOptions,
+    ) -> impl Stream<Item = Result<SearchResult<D>, Error<documents_api::SearchCollectionError>>> + 's
+    {
+        /// The page size Typesense uses when `per_page` is not set.
+        const DEFAULT_PER_PAGE: i32 = 10;
+
+        let (client, collection_name) = (self.client, self.collection_name);
+        let first_page = params.page.unwrap_or(1).max(1);
+        let per_page = params.per_page.unwrap_or(DEFAULT_PER_PAGE).max(1);
+        let fetch = move |page: i32| {
+            let params = raw_models::SearchParameters {
+                page: Some(page),
+                ..params.clone()
+            };
+            Box::pin(async move {
+                Documents::<D>::new(client, collection_name)
+                    .search(params)
+                    .await
+            })
+        };
+
+        // The state holds the page to yield next, the last page once the first response is in,
+        // and the request for the next page if it was already started.
+        let initial = (first_page, None::<i32>, None);
+        stream::try_unfold(initial, move |(page, last_page, pending)| {
+            let fetch = fetch.clone();
+            async move {
+                if last_page.is_some_and(|last_page| page > last_page) {
+                    return Ok(None);
+                }
+                let current = pending.unwrap_or_else(|| Either::Left(fetch(page)));
+                // Until the first response shows how many pages there are, nothing is prefetched.
+                let has_next = last_page.is_some_and(|last_page| page < last_page);
+                let (result, pending) = if options.prefetch && has_next {
+                    // The next page's request is polled along with this one, so it is already on
+                    // its way when this page is yielded.
+                    match future::select(current, fetch(page + 1)).await {
+                        Either::Left((result, next)) => (result, Some(Either::Left(next))),
+                        Either::Right((next, current)) => {
+                            (current.await, Some(Either::Right(future::ready(next))))
+                        }
+                    }
+                } else {
+                    (current.await, None)
+                };
+                let result = result?;
+                let last_page = last_page.unwrap_or_else(|| {
+                    let found = result.found.unwrap_or_default();
+                    (found + per_page - 1) / per_page
+                });
+                Ok(Some((result, (page + 1, Some(last_page), pending))))
+            }
+        })
+    }
+
     /// Deletes a batch of documents matching a specific filter condition.
     ///
     /// # Arguments
@@ -132,12 +860,21 @@ where
     /// Searches for documents in the collection that match the given criteria.
     /// The search results will have their `document` field deserialized into type `D`.
     ///
+    /// Search parameters are sent in the query string. When the encoded query string would be
+    /// longer than the client's `max_search_query_length`, e.g. because of a long `filter_by` list
+    /// or a large `vector_query`, the search is sent in the body of a POST `multi_search` request
+    /// instead, and its single result is returned.
+    ///
     /// # Arguments
     /// * `params` - A `SearchParameters` struct containing all search parameters.
     pub async fn search(
         &self,
         params: raw_models::SearchParameters<'_>,
     ) -> Result<SearchResult<D>, Error<documents_api::SearchCollectionError>> {
+        if encoded_query_length(&params)? > self.client.max_search_query_length {
+            return self.search_with_post(params).await;
+        }
+
         let search_params = documents_api::SearchCollectionParams {
             collection_name: self.collection_name.into(),
 
@@ -217,6 +954,273 @@ where
         };
         execute_wrapper!(self, documents_api::search_collection, search_params)
     }
+
+    /// Sends a search as the only search of a `multi_search` request.
+    async fn search_with_post(
+        &self,
+        params: raw_models::SearchParameters<'_>,
+    ) -> Result<SearchResult<D>, Error<documents_api::SearchCollectionError>> {
+        let search_requests = MultiSearchBody {
+            searches: vec![multi_search_collection_parameters(
+                self.collection_name,
+                params,
+            )],
+        };
+        let mut result = self
+            .client
+            .multi_search()
+            .perform(
+                search_requests,
+                raw_models::MultiSearchParameters::default(),
+            )
+            .await
+            .map_err(into_search_error)?;
+        if result.results.is_empty() {
+            return Err(<serde_json::Error as serde::de::Error>::custom(
+                "multi_search returned no result",
+            )
+            .into());
+        }
+        let item = result.results.swap_remove(0);
+
+        // A failed search is reported inside the result, with the status it would have had.
+        if let Some(message) = item.error {
+            let status = item
+                .code
+                .and_then(|code| u16::try_from(code).ok())
+                .and_then(|code| reqwest::StatusCode::from_u16(code).ok())
+                .unwrap_or(reqwest::StatusCode::BAD_REQUEST);
+            let response = raw_models::ApiResponse { message };
+            let entity = match status.as_u16() {
+                400 => documents_api::SearchCollectionError::Status400(response.clone()),
+                404 => documents_api::SearchCollectionError::Status404(response.clone()),
+                _ => documents_api::SearchCollectionError::UnknownValue(serde_json::to_value(
+                    &response,
+                )?),
+            };
+            return Err(Error::Api(ApiError::ResponseError(ResponseContent {
+                status,
+                content: serde_json::to_string(&response)?,
+                entity: Some(entity),
+            })));
+        }
+
+        Ok(serde_json::from_value(serde_json::to_value(item)?)?)
+    }
+}
+
+/// Sends the request of `documents_api::export_documents`, without reading the response body.
+async fn export_documents_response(
+    configuration: &configuration::Configuration,
+    params: &documents_api::ExportDocumentsParams<'_>,
+) -> Result<reqwest::Response, ApiError<documents_api::ExportDocumentsError>> {
+    let uri = format!(
+        "{}/collections/{}/documents/export",
+        configuration.base_path,
+        apis::urlencode(&params.collection_name)
+    );
+    let mut request = configuration.client.request(reqwest::Method::GET, &uri);
+    for (name, value) in [
+        ("filter_by", &params.filter_by),
+        ("include_fields", &params.include_fields),
+        ("exclude_fields", &params.exclude_fields),
+    ] {
+        if let Some(value) = value {
+            request = request.query(&[(name, value.as_ref())]);
+        }
+    }
+    if let Some(user_agent) = &configuration.user_agent {
+        request = request.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(api_key) = &configuration.api_key {
+        request = request.header("X-TYPESENSE-API-KEY", api_key.key.as_str());
+    }
+
+    let response = configuration.client.execute(request.build()?).await?;
+    let status = response.status();
+    if status.is_client_error() || status.is_server_error() {
+        let content = response.text().await?;
+        let entity = serde_json::from_str(&content).ok();
+        return Err(ApiError::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }));
+    }
+    Ok(response)
+}
+
+/// Splits a JSONL response body into its non-empty lines, while the chunks of the body arrive.
+fn jsonl_lines<B, E>(
+    body: impl Stream<Item = reqwest::Result<B>>,
+) -> impl Stream<Item = Result<String, Error<E>>>
+where
+    B: AsRef<[u8]>,
+    E: std::fmt::Debug + 'static,
+    ApiError<E>: std::error::Error + 'static,
+{
+    let body = Box::pin(body);
+    stream::try_unfold(
+        (body, Vec::new(), false),
+        |(mut body, mut buffer, mut body_done)| async move {
+            loop {
+                let line = match buffer.iter().position(|&byte| byte == b'\n') {
+                    Some(end) => buffer.drain(..=end).collect(),
+                    None if body_done => std::mem::take(&mut buffer),
+                    None => {
+                        match body.next().await {
+                            Some(chunk) => buffer.extend_from_slice(
+                                chunk
+                                    .map_err(|e| Error::Api(ApiError::Reqwest(e)))?
+                                    .as_ref(),
+                            ),
+                            None => body_done = true,
+                        }
+                        continue;
+                    }
+                };
+                if line.is_empty() {
+                    return Ok(None);
+                }
+                let line = String::from_utf8(line).map_err(|e| {
+                    Error::Api(ApiError::Io(std::io::Error::new(
+                        std::io::ErrorKind::InvalidData,
+                        e,
+                    )))
+                })?;
+                let line = line.trim();
+                if !line.is_empty() {
+                    return Ok(Some((line.to_owned(), (body, buffer, body_done))));
+                }
+            }
+        },
+    )
+}
+
+/// The length of the query string that `documents_api::search_collection` would send.
+///
+/// The parameters are form-encoded directly, the way a query string is built from them.
+fn encoded_query_length(
+    params: &raw_models::SearchParameters<'_>,
+) -> Result<usize, serde_json::Error> {
+    serde_urlencoded::to_string(params)
+        .map(|query| query.len())
+        .map_err(<serde_json::Error as serde::ser::Error>::custom)
+}
+
+/// Converts an error of a `multi_search` request into the error of the search it was sent for.
+fn into_search_error(
+    err: Error<documents_api::MultiSearchError>,
+) -> Error<documents_api::SearchCollectionError> {
+    let convert = |err: ApiError<documents_api::MultiSearchError>| match err {
+        ApiError::Reqwest(e) => ApiError::Reqwest(e),
+        #[cfg(not(target_family = "wasm"))]
+        ApiError::ReqwestMiddleware(e) => ApiError::ReqwestMiddleware(e),
+        ApiError::Serde(e) => ApiError::Serde(e),
+        ApiError::Io(e) => ApiError::Io(e),
+        ApiError::ResponseError(response) => ApiError::ResponseError(ResponseContent {
+            status: response.status,
+            content: response.content,
+            entity: response.entity.map(|entity| match entity {
+                documents_api::MultiSearchError::Status400(response) => {
+                    documents_api::SearchCollectionError::Status400(response)
+                }
+                documents_api::MultiSearchError::UnknownValue(value) => {
+                    documents_api::SearchCollectionError::UnknownValue(value)
+                }
+            }),
+        }),
+    };
+    match err {
+        Error::AllNodesFailed { source } => Error::AllNodesFailed {
+            source: convert(source),
+        },
+        Error::Api(err) => Error::Api(convert(err)),
+        Error::Deserialization(err) => Error::Deserialization(err),
+    }
+}
+
+/// Maps the parameters of a single-collection search to a search of a `multi_search` request.
+fn multi_search_collection_parameters<'a>(
+    collection_name: &str,
+    params: raw_models::SearchParameters<'a>,
+) -> raw_models::MultiSearchCollectionParameters<'a> {
+    raw_models::MultiSearchCollectionParameters {
+        collection: Some(collection_name.to_owned().into()),
+        cache_ttl: params.cache_ttl,
+        conversation: params.conversation,
+        conversation_id: params.conversation_id,
+        conversation_model_id: params.conversation_model_id,
+        drop_tokens_mode: params.drop_tokens_mode,
+        drop_tokens_threshold: params.drop_tokens_threshold,
+        enable_highlight_v1: params.enable_highlight_v1,
+        enable_curations: params.enable_curations,
+        enable_synonyms: params.enable_synonyms,
+        enable_typos_for_alpha_numerical_tokens: params.enable_typos_for_alpha_numerical_tokens,
+        enable_typos_for_numerical_tokens: params.enable_typos_for_numerical_tokens,
+        exclude_fields: params.exclude_fields,
+        exhaustive_search: params.exhaustive_search,
+        facet_by: params.facet_by,
+        facet_query: params.facet_query,
+        facet_return_parent: params.facet_return_parent,
+        facet_strategy: params.facet_strategy,
+        filter_by: params.filter_by,
+        filter_curated_hits: params.filter_curated_hits,
+        group_by: params.group_by,
+        group_limit: params.group_limit,
+        group_missing_values: params.group_missing_values,
+        hidden_hits: params.hidden_hits,
+        highlight_affix_num_tokens: params.highlight_affix_num_tokens,
+        highlight_end_tag: params.highlight_end_tag,
+        highlight_fields: params.highlight_fields,
+        highlight_full_fields: params.highlight_full_fields,
+        highlight_start_tag: params.highlight_start_tag,
+        include_fields: params.include_fields,
+        infix: params.infix,
+        limit: params.limit,
+        max_candidates: params.max_candidates,
+        max_extra_prefix: params.max_extra_prefix,
+        max_extra_suffix: params.max_extra_suffix,
+        max_facet_values: params.max_facet_values,
+        max_filter_by_candidates: params.max_filter_by_candidates,
+        min_len_1typo: params.min_len_1typo,
+        min_len_2typo: params.min_len_2typo,
+        num_typos: params.num_typos,
+        offset: params.offset,
+        curation_tags: params.curation_tags,
+        page: params.page,
+        per_page: params.per_page,
+        pinned_hits: params.pinned_hits,
+        pre_segmented_query: params.pre_segmented_query,
+        prefix: params.prefix,
+        preset: params.preset,
+        prioritize_exact_match: params.prioritize_exact_match,
+        prioritize_num_matching_fields: params.prioritize_num_matching_fields,
+        prioritize_token_position: params.prioritize_token_position,
+        q: params.q,
+        query_by: params.query_by,
+        query_by_weights: params.query_by_weights,
+        remote_embedding_num_tries: params.remote_embedding_num_tries,
+        remote_embedding_timeout_ms: params.remote_embedding_timeout_ms,
+        search_cutoff_ms: params.search_cutoff_ms,
+        snippet_threshold: params.snippet_threshold,
+        sort_by: params.sort_by,
+        split_join_tokens: params.split_join_tokens,
+        stopwords: params.stopwords,
+        synonym_num_typos: params.synonym_num_typos,
+        synonym_prefix: params.synonym_prefix,
+        text_match_type: params.text_match_type,
+        typo_tokens_threshold: params.typo_tokens_threshold,
+        use_cache: params.use_cache,
+        validate_field_names: params.validate_field_names,
+        vector_query: params.vector_query,
+        voice_query: params.voice_query,
+        nl_model_id: params.nl_model_id,
+        nl_query: params.nl_query,
+        enable_analytics: params.enable_analytics,
+        synonym_sets: params.synonym_sets,
+        ..Default::default()
+    }
 }
 
 impl<'d, D> Documents<'d, D>
@@ -237,8 +1241,7 @@ where
         document: &D,
         params: Option<DocumentIndexParameters>,
     ) -> Result<D, Error<documents_api::IndexDocumentError>> {
-        let doc_value = serde_json::to_value(document)?;
-        let result_value = self.index(doc_value, "create", params).await?;
+        let result_value = self.index(document, IndexAction::Create, params).await?;
         serde_json::from_value(result_value).map_err(Error::from)
     }
 
@@ -255,8 +1258,24 @@ where
         document: &D,
         params: Option<DocumentIndexParameters>,
     ) -> Result<D, Error<documents_api::IndexDocumentError>> {
-        let doc_value = serde_json::to_value(document)?;
-        let result_value = self.index(doc_value, "upsert", params).await?;
+        let result_value = self.index(document, IndexAction::Upsert, params).await?;
+        serde_json::from_value(result_value).map_err(Error::from)
+    }
+
+    /// Creates a new document, or updates the fields of an existing one if an ID match is found.
+    ///
+    /// Unlike `upsert`, fields of an existing document that are not part of `document` are kept.
+    /// The indexed document is returned.
+    ///
+    /// # Arguments
+    /// * `document` - A document struct to emplace.
+    /// * `params` - Optional parameters like `dirty_values`.
+    pub async fn emplace(
+        &self,
+        document: &D,
+        params: Option<DocumentIndexParameters>,
+    ) -> Result<D, Error<documents_api::IndexDocumentError>> {
+        let result_value = self.index(document, IndexAction::Emplace, params).await?;
         serde_json::from_value(result_value).map_err(Error::from)
     }
 
@@ -264,7 +1283,8 @@ where
     ///
     /// # Arguments
     /// * `document` - A struct containing the fields to update.
-    /// * `params` - A `UpdateDocumentsParameters` describing the conditions for updating documents.
+    /// * `params` - A `UpdateDocumentsParameters` describing the conditions for updating documents,
+    ///   and optionally how to handle `dirty_values`.
     pub async fn update(
         &self,
         document: &D::Partial,
@@ -274,6 +1294,7 @@ where
         let params = documents_api::UpdateDocumentsParams {
             collection_name: self.collection_name.into(),
             filter_by: params.filter_by,
+            dirty_values: params.dirty_values,
             body: document,
         };
         execute_wrapper!(self, documents_api::update_documents, params)
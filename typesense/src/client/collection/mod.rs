@@ -5,8 +5,16 @@
 mod document;
 mod documents;
 
-use crate::{Client, Error, execute_wrapper};
+use crate::{
+    Client, CloneError, EnsureError, Error, SchemaChangeWaitError, execute_wrapper,
+    models::{
+        CollectionCloneOptions, CollectionCloneReport, EnsureOutcome, EnsurePolicy, ImportTally,
+        ReindexSource, SchemaChangeWaitOptions, SchemaDiff, SynonymSetCloneMode,
+    },
+    traits,
+};
 use ::std::borrow::Cow;
+use futures::{TryStreamExt, stream::TryChunksError};
 use serde::de::DeserializeOwned;
 use typesense_codegen::{apis::collections_api, models};
 
@@ -36,6 +44,12 @@ where
         }
     }
 
+    /// The name of the collection.
+    #[inline]
+    pub(crate) fn name(&self) -> &str {
+        &self.collection_name
+    }
+
     /// Provides access to the document-related API endpoints for a specific collection.
     #[inline]
     pub fn documents<'d>(&'d self) -> documents::Documents<'d, D> {
@@ -88,4 +102,464 @@ where
         };
         execute_wrapper!(self, collections_api::update_collection, params)
     }
+
+    /// Copies this collection to a new collection named `new_name`, e.g. for staging or load tests.
+    ///
+    /// The schema of the new collection is taken from `retrieve`. The synonym sets it references
+    /// are copied, shared or skipped, depending on `options.synonym_sets`. Then the documents are
+    /// exported and imported into the new collection in batches of `options.batch_size`,
+    /// optionally restricted to those matching `options.filter_by`. The export is streamed, so at
+    /// most one batch of documents is held in memory at a time. Fields listed in
+    /// `options.exclude_fields` are left out of both the schema and the documents.
+    ///
+    /// If any step fails, the new collection and the copied synonym sets are deleted again.
+    /// Documents that fail to import do not abort the copy; they are counted in the report.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use typesense::{Client, models::CollectionCloneOptions};
+    /// # use reqwest::Url;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::builder()
+    /// #    .nodes(vec![Url::parse("http://localhost:8108").unwrap()])
+    /// #    .api_key("xyz")
+    /// #    .build()
+    /// #    .unwrap();
+    /// let options = CollectionCloneOptions::builder()
+    ///     .filter_by("country:=US")
+    ///     .exclude_fields(vec!["embedding".to_owned()])
+    ///     .build();
+    /// let report = client
+    ///     .collection_schemaless("products")
+    ///     .clone_to("products_staging", options)
+    ///     .await?;
+    /// println!("Copied {} documents", report.documents);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn clone_to(
+        &self,
+        new_name: impl Into<String>,
+        options: CollectionCloneOptions,
+    ) -> Result<CollectionCloneReport, CloneError> {
+        let new_name = new_name.into();
+        let source = self.retrieve().await.map_err(CloneError::Retrieve)?;
+        let is_excluded = |name: &str| {
+            options.exclude_fields.iter().any(|excluded| {
+                name.strip_prefix(excluded.as_str())
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+            })
+        };
+
+        let mut synonym_sets = Vec::new();
+        let mut copied_synonym_sets = Vec::new();
+        for name in source.synonym_sets.iter().flatten() {
+            match options.synonym_sets {
+                SynonymSetCloneMode::Skip => {}
+                SynonymSetCloneMode::Share => synonym_sets.push(name.clone()),
+                SynonymSetCloneMode::Copy => {
+                    let copy = format!("{name}_{new_name}");
+                    if let Err(err) = self.copy_synonym_set(name, &copy).await {
+                        self.delete_synonym_sets(&copied_synonym_sets).await;
+                        return Err(err);
+                    }
+                    copied_synonym_sets.push(copy.clone());
+                    synonym_sets.push(copy);
+                }
+            }
+        }
+
+        let schema = models::CollectionSchema {
+            name: new_name.as_str().into(),
+            fields: source
+                .fields
+                .into_iter()
+                .filter(|field| !is_excluded(&field.name))
+                .collect(),
+            default_sorting_field: source
+                .default_sorting_field
+                .filter(|field| !field.is_empty() && !is_excluded(field))
+                .map(Into::into),
+            token_separators: source.token_separators,
+            synonym_sets: (!synonym_sets.is_empty()).then(|| synonym_sets.clone()),
+            enable_nested_fields: source.enable_nested_fields,
+            symbols_to_index: source.symbols_to_index,
+            voice_query_model: source.voice_query_model,
+            metadata: source.metadata,
+        };
+        let collection = match self.client.collections().create(schema).await {
+            Ok(collection) => collection,
+            Err(err) => {
+                self.delete_synonym_sets(&copied_synonym_sets).await;
+                return Err(CloneError::CreateCollection(err));
+            }
+        };
+
+        let tally = match self.copy_documents(&new_name, &options).await {
+            Ok(tally) => tally,
+            Err(err) => {
+                let _ = self.client.collection_schemaless(&new_name).delete().await;
+                self.delete_synonym_sets(&copied_synonym_sets).await;
+                return Err(err);
+            }
+        };
+
+        Ok(CollectionCloneReport {
+            collection,
+            synonym_sets,
+            documents: tally.submitted - tally.failed,
+            failed: tally.failed,
+            first_error: tally.first_error,
+        })
+    }
+
+    /// Copies the items of the synonym set `name` to a new synonym set `copy`, for `clone_to`.
+    async fn copy_synonym_set(&self, name: &str, copy: &str) -> Result<(), CloneError> {
+        let items = self
+            .client
+            .synonym_set(name)
+            .retrieve()
+            .await
+            .map_err(|source| CloneError::RetrieveSynonymSet {
+                name: name.to_owned(),
+                source,
+            })?
+            .items;
+        self.client
+            .synonym_sets()
+            .upsert(copy, models::SynonymSetCreateSchema::new(items))
+            .await
+            .map_err(|source| CloneError::UpsertSynonymSet {
+                name: copy.to_owned(),
+                source,
+            })?;
+        Ok(())
+    }
+
+    /// Exports the documents of this collection into the collection `new_name`, for `clone_to`.
+    async fn copy_documents(
+        &self,
+        new_name: &str,
+        options: &CollectionCloneOptions,
+    ) -> Result<ImportTally, CloneError> {
+        let lines = self
+            .documents()
+            .export_jsonl_lines(models::ExportDocumentsParameters {
+                filter_by: options.filter_by.as_deref().map(Into::into),
+                exclude_fields: (!options.exclude_fields.is_empty())
+                    .then(|| options.exclude_fields.join(",").into()),
+                ..Default::default()
+            })
+            .await
+            .map_err(CloneError::Export)?
+            .try_chunks(options.batch_size.max(1))
+            .map_err(|TryChunksError(_, err)| CloneError::Export(err));
+        let mut lines = std::pin::pin!(lines);
+
+        let target = self.client.collection_schemaless(new_name);
+        let mut tally = ImportTally::default();
+        while let Some(chunk) = lines.try_next().await? {
+            let response = target
+                .documents()
+                .import_jsonl(chunk.join("\n"), options.import_parameters.clone())
+                .await
+                .map_err(CloneError::Import)?;
+            tally
+                .record(&response)
+                .map_err(|err| CloneError::Import(Error::from(err)))?;
+        }
+        Ok(tally)
+    }
+
+    /// Deletes the synonym sets that `clone_to` copied before it failed, ignoring errors.
+    async fn delete_synonym_sets(&self, names: &[String]) {
+        for name in names {
+            let _ = self.client.synonym_set(name).delete().await;
+        }
+    }
+
+    /// Updates the schema of the collection and waits until Typesense finished applying the alter.
+    ///
+    /// See `wait_for_schema_changes` for how the alter is awaited.
+    ///
+    /// # Arguments
+    /// * `update_schema` - The fields to add, drop or change.
+    /// * `options` - The timeout and the polling interval.
+    /// * `on_progress` - Called with the status of the alter after every poll that found it running.
+    pub async fn update_and_wait(
+        &self,
+        update_schema: models::CollectionUpdateSchema,
+        options: SchemaChangeWaitOptions,
+        on_progress: impl FnMut(&models::SchemaChangeStatus),
+    ) -> Result<models::CollectionUpdateSchema, SchemaChangeWaitError> {
+        let updated = self
+            .update(update_schema)
+            .await
+            .map_err(SchemaChangeWaitError::Update)?;
+        self.wait_for_schema_changes(options, on_progress).await?;
+        Ok(updated)
+    }
+
+    /// Waits until no schema alter is running on this collection anymore.
+    ///
+    /// Polls `Operations::get_schema_changes` every `poll_interval` until it no longer lists the
+    /// collection, and fails with `SchemaChangeWaitError::Timeout` once `timeout` elapsed.
+    /// The collection is looked up once first, so waiting through an alias works too.
+    ///
+    /// # Arguments
+    /// * `options` - The timeout and the polling interval.
+    /// * `on_progress` - Called with the status of the alter after every poll that found it running.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use typesense::{Client, models::SchemaChangeWaitOptions};
+    /// # use reqwest::Url;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::builder()
+    /// #    .nodes(vec![Url::parse("http://localhost:8108").unwrap()])
+    /// #    .api_key("xyz")
+    /// #    .build()
+    /// #    .unwrap();
+    /// client
+    ///     .collection_schemaless("books")
+    ///     .wait_for_schema_changes(SchemaChangeWaitOptions::default(), |status| {
+    ///         println!(
+    ///             "validated {:?}, altered {:?} documents",
+    ///             status.validated_docs, status.altered_docs
+    ///         );
+    ///     })
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn wait_for_schema_changes(
+        &self,
+        options: SchemaChangeWaitOptions,
+        mut on_progress: impl FnMut(&models::SchemaChangeStatus),
+    ) -> Result<(), SchemaChangeWaitError> {
+        let collection_name = self
+            .retrieve()
+            .await
+            .map_err(SchemaChangeWaitError::Retrieve)?
+            .name;
+        let started = web_time::Instant::now();
+
+        loop {
+            let status = self
+                .client
+                .operations()
+                .get_schema_changes()
+                .await
+                .map_err(SchemaChangeWaitError::Poll)?
+                .unwrap_or_default()
+                .into_iter()
+                .find(|status| status.collection.as_deref() == Some(collection_name.as_str()));
+            let Some(status) = status else {
+                return Ok(());
+            };
+            on_progress(&status);
+
+            let elapsed = started.elapsed();
+            if elapsed >= options.timeout {
+                return Err(SchemaChangeWaitError::Timeout {
+                    elapsed,
+                    last_status: status,
+                });
+            }
+            crate::client::sleep(options.poll_interval.min(options.timeout - elapsed)).await;
+        }
+    }
+}
+
+impl<'c, D> Collection<'c, D>
+where
+    D: traits::Document,
+{
+    /// Compares the schema derived for `D` with the live collection.
+    ///
+    /// The returned `SchemaDiff` lists added, removed and changed fields, provides the
+    /// `CollectionUpdateSchema` that migrates the collection in place, and flags the
+    /// differences that require a reindex instead.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use serde::{Serialize, Deserialize};
+    /// # use typesense::{Client, Typesense};
+    /// # use reqwest::Url;
+    /// # #[derive(Typesense, Serialize, Deserialize)]
+    /// # struct Book { id: String, title: String }
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::builder()
+    /// #    .nodes(vec![Url::parse("http://localhost:8108").unwrap()])
+    /// #    .api_key("xyz")
+    /// #    .build()
+    /// #    .unwrap();
+    /// let collection = client.collection::<Book>();
+    /// let diff = collection.schema_diff().await?;
+    /// if !diff.requires_reindex()
+    ///     && let Some(update_schema) = diff.update_schema()
+    /// {
+    ///     collection.update(update_schema).await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn schema_diff(
+        &self,
+    ) -> Result<SchemaDiff, Error<collections_api::GetCollectionError>> {
+        let live = self.retrieve().await?;
+        Ok(SchemaDiff::between(&live, &D::collection_schema()))
+    }
+
+    /// Makes sure the collection exists and, depending on `policy`, matches the schema derived for `D`.
+    ///
+    /// A missing collection is created. An existing one is compared with the schema using
+    /// `schema_diff` and then left alone, rejected, updated in place or re-indexed, as the
+    /// policy describes. Creating a collection that was concurrently created elsewhere is
+    /// treated as finding it existing.
+    ///
+    /// # Returns
+    /// An `EnsureOutcome` describing what was done.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use serde::{Serialize, Deserialize};
+    /// # use typesense::{Client, Typesense, models::{EnsureOutcome, EnsurePolicy, SchemaChangeWaitOptions}};
+    /// # use reqwest::Url;
+    /// # #[derive(Typesense, Serialize, Deserialize)]
+    /// # struct Book { id: String, title: String }
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::builder()
+    /// #    .nodes(vec![Url::parse("http://localhost:8108").unwrap()])
+    /// #    .api_key("xyz")
+    /// #    .build()
+    /// #    .unwrap();
+    /// let policy = EnsurePolicy::UpdateInPlace(SchemaChangeWaitOptions::default());
+    /// match client.collection::<Book>().ensure(policy).await? {
+    ///     EnsureOutcome::Created(_) => println!("Created the books collection"),
+    ///     EnsureOutcome::Updated(diff) => println!("Migrated the books collection: {diff:?}"),
+    ///     _ => {}
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn ensure(&self, policy: EnsurePolicy) -> Result<EnsureOutcome, EnsureError> {
+        let mut schema = D::collection_schema();
+        schema.name = self.collection_name.as_ref().to_owned().into();
+
+        let live = match self.retrieve().await {
+            Ok(live) => live,
+            Err(err) if err.status() == Some(reqwest::StatusCode::NOT_FOUND) => {
+                match self.create_missing(schema.clone(), &policy).await {
+                    Ok(created) => return Ok(EnsureOutcome::Created(created)),
+                    Err(EnsureError::Create(err))
+                        if err.status() == Some(reqwest::StatusCode::CONFLICT) =>
+                    {
+                        self.retrieve().await.map_err(EnsureError::Retrieve)?
+                    }
+                    Err(err) => return Err(err),
+                }
+            }
+            Err(err) => return Err(EnsureError::Retrieve(err)),
+        };
+
+        if matches!(policy, EnsurePolicy::CreateOnly) {
+            return Ok(EnsureOutcome::Exists);
+        }
+        let diff = SchemaDiff::between(&live, &schema);
+        if diff.is_empty() {
+            return Ok(EnsureOutcome::UpToDate);
+        }
+
+        match policy {
+            EnsurePolicy::CreateOnly => Ok(EnsureOutcome::Exists),
+            EnsurePolicy::FailOnDrift => Err(EnsureError::Drift(Box::new(diff))),
+            EnsurePolicy::UpdateInPlace(options) => {
+                if diff.requires_reindex() {
+                    return Err(EnsureError::RequiresReindex(Box::new(diff)));
+                }
+                if let Some(update_schema) = diff.update_schema() {
+                    self.update_and_wait(update_schema, options, |_| {})
+                        .await
+                        .map_err(EnsureError::Update)?;
+                }
+                Ok(EnsureOutcome::Updated(diff))
+            }
+            EnsurePolicy::Reindex(options) => {
+                let alias = self.client.alias(&self.collection_name);
+                match alias.retrieve().await {
+                    Ok(_) => {}
+                    Err(err) if err.status() == Some(reqwest::StatusCode::NOT_FOUND) => {
+                        return Err(EnsureError::NotAnAlias(self.collection_name.to_string()));
+                    }
+                    Err(err) => return Err(EnsureError::RetrieveAlias(err)),
+                }
+
+                schema.name = self.unused_versioned_name().await?.into();
+                let report = alias
+                    .reindex(schema, ReindexSource::export(), options)
+                    .await?;
+                Ok(EnsureOutcome::Reindexed(report))
+            }
+        }
+    }
+
+    /// Creates the collection for `ensure`. With `EnsurePolicy::Reindex`, a versioned collection
+    /// is created behind an alias instead.
+    async fn create_missing(
+        &self,
+        mut schema: models::CollectionSchema<'_>,
+        policy: &EnsurePolicy,
+    ) -> Result<models::CollectionResponse, EnsureError> {
+        if !matches!(policy, EnsurePolicy::Reindex(_)) {
+            return self
+                .client
+                .collections()
+                .create(schema)
+                .await
+                .map_err(EnsureError::Create);
+        }
+
+        schema.name = self.unused_versioned_name().await?.into();
+        let created = self
+            .client
+            .collections()
+            .create(schema)
+            .await
+            .map_err(EnsureError::Create)?;
+        self.client
+            .aliases()
+            .upsert(
+                self.collection_name.as_ref(),
+                models::CollectionAliasSchema::new(created.name.as_str().into()),
+            )
+            .await
+            .map_err(EnsureError::CreateAlias)?;
+        Ok(created)
+    }
+
+    /// The name of a new version of the collection behind the alias, e.g. `books_1700000000123`.
+    ///
+    /// The suffix is the current unix time in milliseconds, bumped until no collection has that name.
+    async fn unused_versioned_name(&self) -> Result<String, EnsureError> {
+        let mut version = web_time::SystemTime::now()
+            .duration_since(web_time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis();
+        loop {
+            let name = format!("{}_{version}", self.collection_name);
+            match self
+                .client
+                .collection_schemaless(name.as_str())
+                .retrieve()
+                .await
+            {
+                Ok(_) => version += 1,
+                Err(err) if err.status() == Some(reqwest::StatusCode::NOT_FOUND) => {
+                    return Ok(name);
+                }
+                Err(err) => return Err(EnsureError::Retrieve(err)),
+            }
+        }
+    }
 }
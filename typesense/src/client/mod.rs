@@ -338,6 +338,7 @@ pub struct Client {
     is_nearest_node_set: bool,
     healthcheck_interval: Duration,
     current_node_index: AtomicUsize,
+    max_search_query_length: usize,
 }
 
 #[bon::bon]
@@ -348,6 +349,7 @@ impl Client {
     /// - **nearest_node**: None.
     /// - **healthcheck_interval**: 60 seconds.
     /// - **retry_policy**: Exponential backoff with a maximum of 3 retries. (disabled on WASM)
+    /// - **max_search_query_length**: 4000 characters.
     /// - **http_builder**: An `FnOnce(reqwest::ClientBuilder) -> reqwest::ClientBuilder` closure
     ///   for per-node HTTP client customization (optional, via [`NodeConfig`]).
     ///
@@ -378,6 +380,11 @@ impl Client {
         #[builder(into, default)]
         /// The retry policy for transient network errors on a *single* node.
         retry_policy: ClientRetryPolicy,
+        #[builder(default = 4_000)]
+        /// The length of the encoded query string above which `Documents::search` is sent as a
+        /// POST `multi_search` request instead, so long `filter_by` or `vector_query` values stay
+        /// within the URL length limits of proxies.
+        max_search_query_length: usize,
     ) -> Result<Self, ClientBuilderError> {
         let is_nearest_node_set = nearest_node.is_some();
 
@@ -445,6 +452,7 @@ impl Client {
             is_nearest_node_set,
             healthcheck_interval,
             current_node_index: AtomicUsize::new(0),
+            max_search_query_length,
         })
     }
 
@@ -1153,3 +1161,14 @@ where
         _ => false,
     }
 }
+
+/// Waits for the given duration without blocking the executor.
+///
+/// Uses `tokio`'s timer on native targets and a browser timer on WASM.
+pub(crate) async fn sleep(duration: Duration) {
+    #[cfg(not(target_arch = "wasm32"))]
+    tokio::time::sleep(duration).await;
+
+    #[cfg(target_arch = "wasm32")]
+    gloo_timers::future::sleep(duration).await;
+}
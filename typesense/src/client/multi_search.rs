@@ -2,10 +2,14 @@
 //!
 //! A `MultiSearch` instance is created via the main `client.multi_search()` method.
 
+use super::Collection;
 use crate::{
     Client, Error, execute_wrapper,
-    models::{MultiSearchBody, SearchResult},
+    models::{MultiSearchBody, MultiSearchCollectionParameters, SearchResult},
+    traits::{MultiSearchDocuments, PushSearch},
 };
+use serde::de::DeserializeOwned;
+use std::marker::PhantomData;
 use typesense_codegen::{
     apis::documents_api::{self, MultiSearchParams},
     models as raw_models,
@@ -133,6 +137,76 @@ impl<'c> MultiSearch<'c> {
         }
     }
 
+    /// Starts a **typed** federated multi-search with a search in `collection`.
+    ///
+    /// More searches are added with `TypedMultiSearch::search`, each in a collection of its own
+    /// document type. `perform` then returns a tuple with the result of every search, parsed
+    /// into its document type. A failed search only fails its own element of the tuple.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # #[cfg(not(target_family = "wasm"))]
+    /// # {
+    /// # use typesense::{Client, models};
+    /// # use serde::Deserialize;
+    /// #
+    /// # #[derive(Deserialize, Debug)]
+    /// # struct Product { id: String, name: String }
+    /// # #[derive(Deserialize, Debug)]
+    /// # struct Brand { id: String, company_name: String }
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::builder()
+    /// #    .nodes(vec!["http://localhost:8108"])
+    /// #    .api_key("xyz")
+    /// #    .build()
+    /// #    .unwrap();
+    /// let products = client.collection_named::<Product>("products");
+    /// let brands = client.collection_named::<Brand>("brands");
+    ///
+    /// let (products, brands) = client
+    ///     .multi_search()
+    ///     .search(
+    ///         &products,
+    ///         models::MultiSearchCollectionParameters::builder()
+    ///             .q("shoe")
+    ///             .query_by("name")
+    ///             .build(),
+    ///     )
+    ///     .search(
+    ///         &brands,
+    ///         models::MultiSearchCollectionParameters::builder()
+    ///             .q("nike")
+    ///             .query_by("company_name")
+    ///             .build(),
+    ///     )
+    ///     .perform(models::MultiSearchParameters::default())
+    ///     .await?;
+    ///
+    /// let products: models::SearchResult<Product> = products?;
+    /// println!("Found {} products.", products.found.unwrap_or(0));
+    /// if let Err(e) = brands {
+    ///     println!("The brand search failed: {e}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// # }
+    /// ```
+    pub fn search<'a, D: DeserializeOwned>(
+        &self,
+        collection: &Collection<'_, D>,
+        search: MultiSearchCollectionParameters<'a>,
+    ) -> TypedMultiSearch<'c, 'a, (D,)> {
+        TypedMultiSearch {
+            client: self.client,
+            searches: Vec::new(),
+            _documents: PhantomData,
+        }
+        .with_search(collection, search)
+    }
+
     /// Performs a multi-search request in **union** mode, returning a single, merged `SearchResult`.
     ///
     /// For more details, see the
@@ -271,6 +345,69 @@ impl<'c> MultiSearch<'c> {
         }
     }
 }
+
+/// A federated multi-search whose searches each parse into their own document type.
+///
+/// This struct is created by calling `client.multi_search().search(...)`. `S` is the tuple of
+/// the document types of the searches, in order.
+pub struct TypedMultiSearch<'c, 'a, S> {
+    client: &'c Client,
+    searches: Vec<MultiSearchCollectionParameters<'a>>,
+    _documents: PhantomData<fn() -> S>,
+}
+
+impl<'c, 'a, S> TypedMultiSearch<'c, 'a, S> {
+    /// Adds a search in `collection`, whose result is parsed into its document type `D`.
+    pub fn search<D: DeserializeOwned>(
+        self,
+        collection: &Collection<'_, D>,
+        search: MultiSearchCollectionParameters<'a>,
+    ) -> TypedMultiSearch<'c, 'a, S::Output>
+    where
+        S: PushSearch<D>,
+    {
+        TypedMultiSearch {
+            client: self.client,
+            searches: self.searches,
+            _documents: PhantomData,
+        }
+        .with_search(collection, search)
+    }
+
+    fn with_search<D: DeserializeOwned>(
+        mut self,
+        collection: &Collection<'_, D>,
+        mut search: MultiSearchCollectionParameters<'a>,
+    ) -> Self {
+        search.collection = Some(collection.name().to_owned().into());
+        self.searches.push(search);
+        self
+    }
+
+    /// Performs the searches, returning the result of every search in a tuple.
+    ///
+    /// The outer `Result` fails when the request as a whole fails. A search that fails on the
+    /// server, or whose documents don't deserialize, fails its own element of the tuple.
+    ///
+    /// # Arguments
+    /// * `common_search_params` - A `MultiSearchParameters` struct describing search parameters that are common to all searches.
+    pub async fn perform(
+        self,
+        common_search_params: raw_models::MultiSearchParameters<'_>,
+    ) -> Result<S::Results, Error<documents_api::MultiSearchError>>
+    where
+        S: MultiSearchDocuments,
+    {
+        let search_requests = MultiSearchBody {
+            searches: self.searches,
+        };
+        let response = MultiSearch::new(self.client)
+            .perform(search_requests, common_search_params)
+            .await?;
+        Ok(S::parse_results(&response))
+    }
+}
+
 // Private helper function to construct the final search parameters object.
 // This encapsulates the repetitive mapping logic.
 fn build_multi_search_params<'a>(
@@ -1,7 +1,11 @@
 //! Contains the error types for the Typesense client
 
+#[cfg(feature = "csv")]
+use crate::models::CsvImportReport;
+use crate::models::{ImportReport, ThrottledImportReport};
 use thiserror::Error;
 pub use typesense_codegen::apis::{Error as ApiError, ResponseContent};
+use typesense_codegen::apis::{collections_api, documents_api, operations_api, synonyms_api};
 
 /// Errors that can occur when building a Typesense client.
 #[derive(Debug, Error)]
@@ -68,6 +72,36 @@ where
     Deserialization(#[from] serde_json::Error),
 }
 
+impl<E> Error<E>
+where
+    E: std::fmt::Debug + 'static,
+    ApiError<E>: std::error::Error + 'static,
+{
+    /// The HTTP status code Typesense answered with, if the request reached the server.
+    pub(crate) fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            Error::Api(ApiError::ResponseError(response))
+            | Error::AllNodesFailed {
+                source: ApiError::ResponseError(response),
+            } => Some(response.status),
+            _ => None,
+        }
+    }
+
+    /// Whether the request may succeed when it is sent again: every node failed with a server
+    /// or network error, or Typesense answered with a timeout (`408`) or a rate limit (`429`).
+    pub(crate) fn is_transient(&self) -> bool {
+        match self {
+            Error::AllNodesFailed { .. } => true,
+            _ => self.status().is_some_and(|status| {
+                status.is_server_error()
+                    || status == reqwest::StatusCode::REQUEST_TIMEOUT
+                    || status == reqwest::StatusCode::TOO_MANY_REQUESTS
+            }),
+        }
+    }
+}
+
 /// Represents the possible errors that can occur when parsing a `multi_search` response.
 ///
 /// This error enum is returned by the `MultiSearchResultExt::parse_at` method when it
@@ -120,3 +154,409 @@ pub enum MultiSearchParseError {
         source: serde_json::Error,
     },
 }
+
+/// Represents the errors that can occur while importing with `Documents::import_jsonl_with_retry`.
+///
+/// Per-line failures are not errors: they are either retried or handed to the dead-letter sink.
+/// Every variant holds the `report` of the lines that were imported or dead-lettered before the
+/// import stopped, and the `unfinished` lines that were neither.
+#[derive(Debug, Error)]
+pub enum ImportRetryError {
+    /// An import request failed as a whole, or its response could not be parsed.
+    #[error("Import request failed: {source}")]
+    Import {
+        /// What was imported before the import stopped.
+        report: ImportReport,
+        /// The lines of the failed request.
+        unfinished: Vec<String>,
+        /// The error of the failed request.
+        #[source]
+        source: Error<documents_api::ImportDocumentsError>,
+    },
+
+    /// Typesense answered with a different number of lines than were submitted,
+    /// so failures can no longer be matched to their documents.
+    #[error("Import response has {received} lines, but {expected} lines were submitted")]
+    ResponseMismatch {
+        /// The number of lines that were submitted.
+        expected: usize,
+        /// The number of lines in the response.
+        received: usize,
+        /// What was imported before the import stopped.
+        report: ImportReport,
+        /// The lines of the request, which may or may not have been imported.
+        unfinished: Vec<String>,
+    },
+
+    /// The dead-letter sink could not accept a failed line.
+    #[error("Failed to write to the dead-letter sink: {source}")]
+    DeadLetter {
+        /// What was imported before the import stopped.
+        report: ImportReport,
+        /// The line the sink did not accept, and the failed lines that were not handled yet.
+        unfinished: Vec<String>,
+        /// The error of the sink.
+        #[source]
+        source: std::io::Error,
+    },
+}
+
+impl ImportRetryError {
+    /// What was imported and dead-lettered before the import stopped.
+    pub fn report(&self) -> &ImportReport {
+        match self {
+            Self::Import { report, .. }
+            | Self::ResponseMismatch { report, .. }
+            | Self::DeadLetter { report, .. } => report,
+        }
+    }
+
+    /// The lines that were neither imported nor dead-lettered.
+    pub fn unfinished(&self) -> &[String] {
+        match self {
+            Self::Import { unfinished, .. }
+            | Self::ResponseMismatch { unfinished, .. }
+            | Self::DeadLetter { unfinished, .. } => unfinished,
+        }
+    }
+}
+
+/// Represents an import request that failed during `Documents::import_jsonl_throttled`.
+///
+/// No further rounds were started after the failed request. `report` holds the responses of
+/// every batch that succeeded, the line ranges of the batches that failed, and how many lines
+/// were sent at all.
+#[derive(Debug, Error)]
+#[error("Import request failed after {} of {} batches: {source}", report.batches - report.failed_batches.len(), report.batches)]
+pub struct ThrottledImportError {
+    /// What was imported before the import stopped.
+    pub report: Box<ThrottledImportReport>,
+    /// The error of the first failed import request.
+    #[source]
+    pub source: Error<documents_api::ImportDocumentsError>,
+}
+
+/// Represents the errors that can occur during `Alias::reindex`.
+///
+/// Unless stated otherwise, the alias was not swapped and still points to the old collection.
+#[derive(Debug, Error)]
+pub enum ReindexError {
+    /// The alias could not be looked up.
+    #[error("Failed to retrieve the alias: {0}")]
+    RetrieveAlias(#[source] Error<collections_api::GetAliasError>),
+
+    /// The source was `ReindexSource::Export`, but the alias does not exist yet.
+    #[error("Cannot export documents: alias `{0}` does not exist")]
+    AliasNotFound(String),
+
+    /// The new collection could not be created.
+    #[error("Failed to create the new collection: {0}")]
+    CreateCollection(#[source] Error<collections_api::CreateCollectionError>),
+
+    /// The documents of the old collection could not be exported.
+    #[error("Failed to export the old collection: {0}")]
+    Export(#[source] Error<documents_api::ExportDocumentsError>),
+
+    /// An import request into the new collection failed.
+    #[error("Failed to import into the new collection: {0}")]
+    Import(#[source] Error<documents_api::ImportDocumentsError>),
+
+    /// The new collection could not be retrieved to validate its document count.
+    #[error("Failed to retrieve the new collection: {0}")]
+    RetrieveCollection(#[source] Error<collections_api::GetCollectionError>),
+
+    /// The new collection does not contain every source document. It was deleted again.
+    #[error(
+        "The new collection has {indexed} documents, but {expected} were provided ({failed} failed to import)"
+    )]
+    CountMismatch {
+        /// The number of documents read from the source.
+        expected: u64,
+        /// The number of documents in the new collection.
+        indexed: u64,
+        /// The number of documents the import reported as failed.
+        failed: u64,
+        /// The first import error, if any.
+        first_error: Option<String>,
+    },
+
+    /// The alias could not be pointed to the new collection.
+    #[error("Failed to swap the alias: {0}")]
+    UpsertAlias(#[source] Error<collections_api::UpsertAliasError>),
+
+    /// The alias was swapped to the new collection, but the old collection could not be deleted.
+    #[error("The alias was swapped, but the old collection could not be deleted: {0}")]
+    DeleteOldCollection(#[source] Error<collections_api::DeleteCollectionError>),
+}
+
+/// Represents the errors that can occur during `Collection::ensure`.
+#[derive(Debug, Error)]
+pub enum EnsureError {
+    /// The collection could not be looked up.
+    #[error("Failed to retrieve the collection: {0}")]
+    Retrieve(#[source] Error<collections_api::GetCollectionError>),
+
+    /// The missing collection could not be created.
+    #[error("Failed to create the collection: {0}")]
+    Create(#[source] Error<collections_api::CreateCollectionError>),
+
+    /// The alias for a newly created, versioned collection could not be created.
+    #[error("Failed to create the alias: {0}")]
+    CreateAlias(#[source] Error<collections_api::UpsertAliasError>),
+
+    /// The alias of the collection could not be looked up.
+    #[error("Failed to retrieve the alias: {0}")]
+    RetrieveAlias(#[source] Error<collections_api::GetAliasError>),
+
+    /// `EnsurePolicy::Reindex` found a plain collection instead of an alias to swap.
+    #[error(
+        "The collection `{0}` is not behind an alias, so it cannot be re-indexed with an alias swap"
+    )]
+    NotAnAlias(String),
+
+    /// The existing collection differs from the schema, and the policy does not allow changes.
+    #[error("The collection differs from the schema")]
+    Drift(Box<crate::models::SchemaDiff>),
+
+    /// The existing collection differs from the schema in ways that cannot be applied in place.
+    #[error("The collection differs from the schema in ways that require a reindex")]
+    RequiresReindex(Box<crate::models::SchemaDiff>),
+
+    /// The in-place update of the collection failed, or did not finish in time.
+    #[error("Failed to update the collection: {0}")]
+    Update(#[source] SchemaChangeWaitError),
+
+    /// Re-indexing into a new collection failed.
+    #[error("Failed to reindex the collection: {0}")]
+    Reindex(#[from] ReindexError),
+}
+
+/// Represents the errors that can occur while waiting for a schema alter with
+/// `Collection::wait_for_schema_changes` or `Collection::update_and_wait`.
+#[derive(Debug, Error)]
+pub enum SchemaChangeWaitError {
+    /// The collection could not be looked up to resolve its name.
+    #[error("Failed to retrieve the collection: {0}")]
+    Retrieve(#[source] Error<collections_api::GetCollectionError>),
+
+    /// The update of the collection schema failed.
+    #[error("Failed to update the collection: {0}")]
+    Update(#[source] Error<collections_api::UpdateCollectionError>),
+
+    /// The schema change status could not be polled.
+    #[error("Failed to poll the schema changes: {0}")]
+    Poll(#[source] Error<operations_api::GetSchemaChangesError>),
+
+    /// The alter was still running when the timeout elapsed. It keeps running on the server.
+    #[error("The schema change was still running after {elapsed:?}")]
+    Timeout {
+        /// How long was waited.
+        elapsed: web_time::Duration,
+        /// The last status reported for the collection.
+        last_status: crate::models::SchemaChangeStatus,
+    },
+}
+
+/// Represents the errors that can occur during `Collection::clone_to`.
+#[derive(Debug, Error)]
+pub enum CloneError {
+    /// The source collection could not be retrieved.
+    #[error("Failed to retrieve the source collection: {0}")]
+    Retrieve(#[source] Error<collections_api::GetCollectionError>),
+
+    /// A synonym set referenced by the source collection could not be retrieved.
+    #[error("Failed to retrieve synonym set `{name}`: {source}")]
+    RetrieveSynonymSet {
+        /// The name of the synonym set.
+        name: String,
+        /// The underlying error.
+        #[source]
+        source: Error<synonyms_api::RetrieveSynonymSetError>,
+    },
+
+    /// A copy of a synonym set could not be created.
+    #[error("Failed to create synonym set `{name}`: {source}")]
+    UpsertSynonymSet {
+        /// The name of the synonym set copy.
+        name: String,
+        /// The underlying error.
+        #[source]
+        source: Error<synonyms_api::UpsertSynonymSetError>,
+    },
+
+    /// The new collection could not be created.
+    #[error("Failed to create the new collection: {0}")]
+    CreateCollection(#[source] Error<collections_api::CreateCollectionError>),
+
+    /// The documents of the source collection could not be exported.
+    #[error("Failed to export the source collection: {0}")]
+    Export(#[source] Error<documents_api::ExportDocumentsError>),
+
+    /// An import request into the new collection failed.
+    #[error("Failed to import into the new collection: {0}")]
+    Import(#[source] Error<documents_api::ImportDocumentsError>),
+}
+
+/// A reason why a document does not conform to a collection schema, as found by `DocumentValidator`.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum DocumentValidationError {
+    /// The document is not a JSON object.
+    #[error("The document is not a JSON object")]
+    NotAnObject,
+
+    /// The document is not valid JSON.
+    #[error("The document is not valid JSON: {0}")]
+    InvalidJson(String),
+
+    /// The document could not be serialized to JSON.
+    #[error("The document could not be serialized: {0}")]
+    Serialization(String),
+
+    /// A field that is not optional is missing or `null`.
+    #[error("Field `{field}` is required but missing")]
+    MissingField {
+        /// The name of the field.
+        field: String,
+    },
+
+    /// A field holds a value that does not match its type in the schema.
+    #[error("Field `{field}` must be of type `{expected}`, but is {found}")]
+    TypeMismatch {
+        /// The name of the field.
+        field: String,
+        /// The type of the field in the schema, e.g. `int32` or `string[]`.
+        expected: String,
+        /// A description of the value that was found, e.g. `a string`.
+        found: &'static str,
+    },
+
+    /// A vector field holds a different number of values than its `num_dim`.
+    #[error("Field `{field}` must have {expected} dimensions, but has {found}")]
+    VectorDimensions {
+        /// The name of the field.
+        field: String,
+        /// The `num_dim` of the field in the schema.
+        expected: usize,
+        /// The number of values that were found.
+        found: usize,
+    },
+
+    /// A `geopoint`, `geopoint[]` or `geopolygon` field holds coordinates that are malformed
+    /// or out of range.
+    #[error("Field `{field}` is not a valid `{expected}`: {reason}")]
+    InvalidGeo {
+        /// The name of the field.
+        field: String,
+        /// The type of the field in the schema.
+        expected: String,
+        /// What is wrong with the coordinates.
+        reason: &'static str,
+    },
+}
+
+/// Represents an error that occurred while building a `GeoPolygon`.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum GeoPolygonError {
+    /// The polygon has fewer than three vertices.
+    #[error("A geopolygon needs at least three vertices, but has {0}")]
+    TooFewVertices(usize),
+
+    /// A flat array of coordinates has an odd length, so its last latitude has no longitude.
+    #[error("A geopolygon must have an even number of coordinates")]
+    UnpairedCoordinate,
+}
+
+/// Represents an error that occurred while building a `FacetSpec`.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum FacetSpecError {
+    /// A range label is empty or has characters other than ASCII letters, digits, `_` and `-`,
+    /// which would change the meaning of the `facet_by` string.
+    #[error("Invalid facet range label `{0}`: only ASCII letters, digits, `_` and `-` are allowed")]
+    InvalidRangeLabel(String),
+}
+
+/// Represents an error that occurred while building a `VectorQuery`.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum VectorQueryError {
+    /// A document id contains a comma, a parenthesis or a backtick, which would end the `id`
+    /// option of the `vector_query` string early.
+    #[error("Invalid document id `{0}`: commas, parentheses and backticks are not allowed")]
+    InvalidId(String),
+}
+
+/// Represents an error that occurred during `Documents::import_csv`.
+#[cfg(feature = "csv")]
+#[derive(Debug, Error)]
+pub enum CsvImportError {
+    /// The collection could not be retrieved to look up its fields.
+    #[error("Failed to retrieve the collection schema: {0}")]
+    Retrieve(#[source] Error<collections_api::GetCollectionError>),
+
+    /// The input is not valid CSV, e.g. a row has a different number of columns than the header.
+    #[error("Invalid CSV in row {row}: {source}")]
+    Csv {
+        /// The line of the row in the input, where the header is line 1.
+        row: u64,
+        /// The batches imported before the row was read. Empty for `CsvImportOptions::to_jsonl`.
+        report: CsvImportReport,
+        /// The underlying error.
+        #[source]
+        source: csv::Error,
+    },
+
+    /// A column listed in `CsvImportOptions::geopoints` is not part of the header.
+    #[error("Column `{column}` is missing from the header")]
+    MissingColumn {
+        /// The header of the missing column.
+        column: String,
+    },
+
+    /// A cell could not be converted to the type of its field.
+    #[error("Row {row}: value `{value}` of column `{column}` is not a valid `{expected}`")]
+    InvalidValue {
+        /// The line of the row in the input, where the header is line 1.
+        row: u64,
+        /// The header of the column.
+        column: String,
+        /// The content of the cell.
+        value: String,
+        /// The type the cell was converted to, e.g. `int32` or `bool[]`.
+        expected: String,
+        /// The batches imported before the row was read. Empty for `CsvImportOptions::to_jsonl`.
+        report: CsvImportReport,
+    },
+
+    /// The import request of a batch failed. The batches before it were imported.
+    #[error("Failed to import the rows on lines {}-{}: {source}", rows.start(), rows.end())]
+    Import {
+        /// The lines of the first and the last row of the failed batch.
+        rows: std::ops::RangeInclusive<u64>,
+        /// The batches imported before the failed one.
+        report: CsvImportReport,
+        /// The underlying error.
+        #[source]
+        source: Error<documents_api::ImportDocumentsError>,
+    },
+}
+
+#[cfg(feature = "csv")]
+impl CsvImportError {
+    /// The batches that were imported before the import stopped, if the error occurred
+    /// after the header was read.
+    pub fn report(&self) -> Option<&CsvImportReport> {
+        match self {
+            Self::Csv { report, .. }
+            | Self::InvalidValue { report, .. }
+            | Self::Import { report, .. } => Some(report),
+            Self::Retrieve(_) | Self::MissingColumn { .. } => None,
+        }
+    }
+
+    /// Attaches the batches imported so far to an error raised while reading a row.
+    pub(crate) fn with_report(mut self, imported: CsvImportReport) -> Self {
+        if let Self::Csv { report, .. } | Self::InvalidValue { report, .. } = &mut self {
+            *report = imported;
+        }
+        self
+    }
+}
@@ -0,0 +1,58 @@
+use typesense_codegen::models::{CollectionResponse, ImportDocumentsParameters, IndexAction};
+
+/// What `Collection::clone_to` does with the synonym sets the source collection references.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SynonymSetCloneMode {
+    /// Copy every referenced synonym set to `{set}_{new collection}`, and reference the copies,
+    /// so the clone's synonyms can be changed without affecting the source.
+    #[default]
+    Copy,
+    /// Reference the same synonym sets as the source collection.
+    Share,
+    /// Do not reference any synonym sets.
+    Skip,
+}
+
+/// Options for `Collection::clone_to`.
+#[derive(Clone, Debug, bon::Builder)]
+#[builder(on(String, into))]
+pub struct CollectionCloneOptions {
+    /// Only copy the documents matching this filter, e.g. `country:=US`.
+    pub filter_by: Option<String>,
+    /// Fields to leave out of both the schema and the documents of the clone.
+    #[builder(default)]
+    pub exclude_fields: Vec<String>,
+    /// What to do with the synonym sets the source collection references.
+    #[builder(default)]
+    pub synonym_sets: SynonymSetCloneMode,
+    /// The number of documents sent in each import request.
+    #[builder(default = 1_000)]
+    pub batch_size: usize,
+    /// The parameters of every import request into the clone.
+    #[builder(default = ImportDocumentsParameters {
+        action: Some(IndexAction::Create),
+        ..Default::default()
+    })]
+    pub import_parameters: ImportDocumentsParameters,
+}
+
+impl Default for CollectionCloneOptions {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+/// Summary of a `Collection::clone_to`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CollectionCloneReport {
+    /// The newly created collection.
+    pub collection: CollectionResponse,
+    /// The synonym sets the new collection references.
+    pub synonym_sets: Vec<String>,
+    /// The number of documents copied into the new collection.
+    pub documents: u64,
+    /// The number of documents that failed to import.
+    pub failed: u64,
+    /// The first import error, if any.
+    pub first_error: Option<String>,
+}
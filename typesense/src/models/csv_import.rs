@@ -0,0 +1,320 @@
+use crate::CsvImportError;
+use serde_json::{Map, Value};
+use std::ops::RangeInclusive;
+use typesense_codegen::models::{Field, ImportDocumentsParameters};
+
+/// A `geopoint` field whose latitude and longitude are stored in two separate CSV columns.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CsvGeopointColumns {
+    /// The name of the `geopoint` field in the collection.
+    pub field: String,
+    /// The header of the column holding the latitude.
+    pub latitude: String,
+    /// The header of the column holding the longitude.
+    pub longitude: String,
+}
+
+impl CsvGeopointColumns {
+    /// Combines the `latitude` and `longitude` columns into the `geopoint` field `field`.
+    pub fn new(
+        field: impl Into<String>,
+        latitude: impl Into<String>,
+        longitude: impl Into<String>,
+    ) -> Self {
+        Self {
+            field: field.into(),
+            latitude: latitude.into(),
+            longitude: longitude.into(),
+        }
+    }
+}
+
+/// Options for `Documents::import_csv`.
+///
+/// The first row of the input must be a header row; every column is mapped to the collection
+/// field of the same name and its cells are converted to that field's type:
+/// * `int32`, `int64` and `float` cells are parsed as numbers,
+/// * `bool` cells accept `true`/`false`, `1`/`0` and `yes`/`no`, in any case,
+/// * array types like `string[]` are split on `array_delimiter`, and every element is converted,
+/// * `geopoint` cells are either a `lat,lng` pair, or are combined from two columns listed in `geopoints`,
+/// * `object`, `object[]`, `geopoint[]` and `geopolygon` cells are parsed as JSON.
+///
+/// Columns without a field, and fields of other types, are imported as strings. Empty cells are
+/// left out of the document, so optional fields can be left blank. Whitespace around numeric,
+/// `bool` and `geopoint` cells is ignored, while string cells are imported as they are.
+#[derive(Clone, Debug, bon::Builder)]
+pub struct CsvImportOptions {
+    /// The delimiter between columns. Use `b'\t'` for TSV.
+    #[builder(default = b',')]
+    pub delimiter: u8,
+    /// The delimiter between the elements of array fields.
+    #[builder(default = '|')]
+    pub array_delimiter: char,
+    /// `geopoint` fields that are combined from separate latitude and longitude columns.
+    #[builder(default)]
+    pub geopoints: Vec<CsvGeopointColumns>,
+    /// The fields to convert the columns to. If unset, the fields are taken from the
+    /// collection's live schema.
+    pub fields: Option<Vec<Field>>,
+    /// The number of rows sent in each import request.
+    #[builder(default = 1_000)]
+    pub batch_size: usize,
+    /// The parameters of every import request.
+    #[builder(default)]
+    pub import_parameters: ImportDocumentsParameters,
+}
+
+impl Default for CsvImportOptions {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+impl CsvImportOptions {
+    /// Options for tab-separated input.
+    pub fn tsv() -> Self {
+        Self::builder().delimiter(b'\t').build()
+    }
+
+    /// Converts CSV input to JSONL documents, using the types of `fields`.
+    ///
+    /// Errors carry the line number of the offending row in the input, where the header is line 1.
+    ///
+    /// # Example
+    /// ```
+    /// use typesense::models::{CsvGeopointColumns, CsvImportOptions, Field};
+    ///
+    /// let fields = vec![
+    ///     Field::builder().name("title").r#type("string").build(),
+    ///     Field::builder().name("year").r#type("int32").build(),
+    ///     Field::builder().name("tags").r#type("string[]").build(),
+    ///     Field::builder().name("location").r#type("geopoint").build(),
+    /// ];
+    /// let options = CsvImportOptions::builder()
+    ///     .geopoints(vec![CsvGeopointColumns::new("location", "lat", "lng")])
+    ///     .build();
+    ///
+    /// let csv = "title,year,tags,lat,lng\nDune,1965,scifi|classic,48.85,2.35\n";
+    /// let jsonl = options.to_jsonl(csv.as_bytes(), &fields).unwrap();
+    /// assert_eq!(
+    ///     serde_json::from_str::<serde_json::Value>(&jsonl).unwrap(),
+    ///     serde_json::json!({
+    ///         "title": "Dune",
+    ///         "year": 1965,
+    ///         "tags": ["scifi", "classic"],
+    ///         "location": [48.85, 2.35],
+    ///     })
+    /// );
+    /// ```
+    pub fn to_jsonl(
+        &self,
+        input: impl std::io::Read,
+        fields: &[Field],
+    ) -> Result<String, CsvImportError> {
+        let mut rows = CsvDocuments::new(self, input, fields)?;
+        let mut lines = Vec::new();
+        while let Some((_, document)) = rows.next_document()? {
+            lines.push(document);
+        }
+        Ok(lines.join("\n"))
+    }
+
+    /// Converts a non-empty cell to a value of the field type `r#type`.
+    fn coerce(&self, r#type: &str, cell: &str) -> Option<Value> {
+        match r#type {
+            "object" | "object[]" | "geopoint[]" | "geopolygon" => serde_json::from_str(cell).ok(),
+            "geopoint" => {
+                let (lat, lng) = cell.split_once(',')?;
+                Some(Value::Array(vec![
+                    coerce_scalar("float", lat.trim())?,
+                    coerce_scalar("float", lng.trim())?,
+                ]))
+            }
+            r#type => match r#type.strip_suffix("[]") {
+                Some(element_type) => cell
+                    .split(self.array_delimiter)
+                    .map(|element| trim_cell(element_type, element))
+                    .filter(|element| !element.is_empty())
+                    .map(|element| coerce_scalar(element_type, element))
+                    .collect::<Option<Vec<_>>>()
+                    .map(Value::Array),
+                None => coerce_scalar(r#type, cell),
+            },
+        }
+    }
+}
+
+/// Reads the rows of CSV input one at a time, and converts each to a JSON document.
+pub(crate) struct CsvDocuments<'o, R> {
+    options: &'o CsvImportOptions,
+    reader: csv::Reader<R>,
+    headers: csv::StringRecord,
+    /// The index, header and field type of every column that is not part of a geopoint.
+    columns: Vec<(usize, String, String)>,
+    /// The field, latitude column and longitude column of every combined geopoint.
+    geopoints: Vec<(String, usize, usize)>,
+    record: csv::StringRecord,
+}
+
+impl<'o, R: std::io::Read> CsvDocuments<'o, R> {
+    /// Reads the header row of `input` and maps its columns to `fields`.
+    pub(crate) fn new(
+        options: &'o CsvImportOptions,
+        input: R,
+        fields: &[Field],
+    ) -> Result<Self, CsvImportError> {
+        let mut reader = csv::ReaderBuilder::new()
+            .delimiter(options.delimiter)
+            .trim(csv::Trim::Headers)
+            .from_reader(input);
+
+        let headers = reader
+            .headers()
+            .map_err(|source| CsvImportError::Csv {
+                row: 1,
+                report: CsvImportReport::default(),
+                source,
+            })?
+            .clone();
+        let column = |name: &str| {
+            headers
+                .iter()
+                .position(|header| header == name)
+                .ok_or_else(|| CsvImportError::MissingColumn {
+                    column: name.to_owned(),
+                })
+        };
+        let geopoints = options
+            .geopoints
+            .iter()
+            .map(|g| Ok((g.field.clone(), column(&g.latitude)?, column(&g.longitude)?)))
+            .collect::<Result<Vec<_>, CsvImportError>>()?;
+        let columns = headers
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| !geopoints.iter().any(|(_, lat, lng)| lat == i || lng == i))
+            .map(|(i, name)| {
+                let field = fields.iter().find(|f| f.name == name);
+                let r#type = field.map_or("string", |f| f.r#type.as_str());
+                (i, name.to_owned(), r#type.to_owned())
+            })
+            .collect();
+
+        Ok(Self {
+            options,
+            reader,
+            headers,
+            columns,
+            geopoints,
+            record: csv::StringRecord::new(),
+        })
+    }
+
+    /// Reads the next row, and returns its line in the input together with its JSON document.
+    pub(crate) fn next_document(&mut self) -> Result<Option<(u64, String)>, CsvImportError> {
+        let row = self.reader.position().line();
+        match self.reader.read_record(&mut self.record) {
+            Ok(true) => {}
+            Ok(false) => return Ok(None),
+            Err(source) => {
+                let row = source.position().map_or(row, |p| p.line());
+                return Err(CsvImportError::Csv {
+                    row,
+                    report: CsvImportReport::default(),
+                    source,
+                });
+            }
+        }
+        let row = self.record.position().map_or(row, |p| p.line());
+        let invalid = |column: &str, value: &str, expected: &str| CsvImportError::InvalidValue {
+            row,
+            column: column.to_owned(),
+            value: value.to_owned(),
+            expected: expected.to_owned(),
+            report: CsvImportReport::default(),
+        };
+
+        let mut document = Map::new();
+        for (i, name, r#type) in &self.columns {
+            let cell = trim_cell(r#type, self.record.get(*i).unwrap_or_default());
+            if cell.is_empty() {
+                continue;
+            }
+            let value = self
+                .options
+                .coerce(r#type, cell)
+                .ok_or_else(|| invalid(name, cell, r#type))?;
+            document.insert(name.clone(), value);
+        }
+        for (field, lat_column, lng_column) in &self.geopoints {
+            let (lat, lng) = (
+                self.record.get(*lat_column).unwrap_or_default().trim(),
+                self.record.get(*lng_column).unwrap_or_default().trim(),
+            );
+            if lat.is_empty() && lng.is_empty() {
+                continue;
+            }
+            let coordinate = |column: usize, cell: &str| {
+                coerce_scalar("float", cell)
+                    .ok_or_else(|| invalid(&self.headers[column], cell, "float"))
+            };
+            let point = vec![coordinate(*lat_column, lat)?, coordinate(*lng_column, lng)?];
+            document.insert(field.clone(), Value::Array(point));
+        }
+
+        Ok(Some((row, Value::Object(document).to_string())))
+    }
+}
+
+/// Summary of an import performed with `Documents::import_csv`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CsvImportReport {
+    /// The import requests that were sent, in the order of the input.
+    pub batches: Vec<CsvImportBatch>,
+}
+
+impl CsvImportReport {
+    /// The number of rows that were sent.
+    pub fn rows(&self) -> usize {
+        self.batches.iter().map(|batch| batch.documents).sum()
+    }
+}
+
+/// A batch of rows sent in one import request by `Documents::import_csv`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CsvImportBatch {
+    /// The lines of the first and the last row of the batch, where the header is line 1.
+    pub rows: RangeInclusive<u64>,
+    /// The number of rows in the batch.
+    pub documents: usize,
+    /// The JSONL import response, with one line per row.
+    pub response: String,
+}
+
+/// Strips the whitespace around a cell of the field type `r#type`, unless it holds strings.
+fn trim_cell<'c>(r#type: &str, cell: &'c str) -> &'c str {
+    match r#type.strip_suffix("[]").unwrap_or(r#type) {
+        "int32" | "int64" | "float" | "bool" | "geopoint" => cell.trim(),
+        _ => cell,
+    }
+}
+
+/// Converts a cell to a value of the non-array type `r#type`.
+/// Unknown types are kept as strings.
+fn coerce_scalar(r#type: &str, cell: &str) -> Option<Value> {
+    match r#type {
+        "int32" => cell.parse::<i32>().ok().map(Value::from),
+        "int64" => cell.parse::<i64>().ok().map(Value::from),
+        "float" => cell
+            .parse::<f64>()
+            .ok()
+            .filter(|n| n.is_finite())
+            .map(Value::from),
+        "bool" => match cell.to_ascii_lowercase().as_str() {
+            "true" | "1" | "yes" => Some(Value::Bool(true)),
+            "false" | "0" | "no" => Some(Value::Bool(false)),
+            _ => None,
+        },
+        _ => Some(Value::String(cell.to_owned())),
+    }
+}
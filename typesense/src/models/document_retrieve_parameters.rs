@@ -0,0 +1,30 @@
+use ::std::borrow::Cow;
+use serde::{Deserialize, Serialize};
+
+/// Parameters for retrieving a single document from a Typesense collection.
+///
+/// These parameters control which fields of the stored document are returned,
+/// which avoids transferring large fields (e.g. embedding vectors) that are not needed.
+///
+/// See the official Typesense documentation:
+/// <https://typesense.org/docs/latest/api/documents.html#retrieve-a-document>
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize, bon::Builder)]
+#[builder(on(Cow<'_, str>, into))]
+pub struct DocumentRetrieveParameters<'a> {
+    /// Comma-separated list of fields from the document to include in the response.
+    #[serde(rename = "include_fields", skip_serializing_if = "Option::is_none")]
+    pub include_fields: Option<Cow<'a, str>>,
+    /// Comma-separated list of fields from the document to exclude from the response.
+    #[serde(rename = "exclude_fields", skip_serializing_if = "Option::is_none")]
+    pub exclude_fields: Option<Cow<'a, str>>,
+}
+
+impl<'a> DocumentRetrieveParameters<'a> {
+    /// Creates a new `DocumentRetrieveParameters`.
+    pub fn new() -> DocumentRetrieveParameters<'a> {
+        DocumentRetrieveParameters {
+            include_fields: None,
+            exclude_fields: None,
+        }
+    }
+}
@@ -0,0 +1,334 @@
+use crate::DocumentValidationError;
+use serde::Serialize;
+use serde_json::Value;
+use typesense_codegen::models::{CollectionResponse, CollectionSchema, Field, IndexAction};
+
+/// Checks documents against a collection schema locally, before they are sent to Typesense.
+///
+/// The checks mirror what Typesense does with `dirty_values: reject`:
+/// * fields that are not `optional` must be present and not `null`,
+/// * values must match the field `type`, including every element of array types like `string[]`,
+/// * vector fields must hold exactly `num_dim` values,
+/// * `geopoint`, `geopoint[]` and `geopolygon` values must be well-formed coordinates.
+///
+/// Fields with a wildcard name (e.g. `.*`), `auto` and `string*` types, and embedding fields
+/// generated by Typesense are not checked. Nested field names like `address.city` are looked up
+/// inside nested objects.
+///
+/// A validator can be used on its own, or passed to `ImportRetryOptions::validator` to reject
+/// invalid lines of an import before it is sent. For other imports, `validate_jsonl` splits
+/// the lines into valid and invalid ones before they are handed to e.g.
+/// `Documents::import_jsonl_throttled`.
+///
+/// # Example
+/// ```
+/// use typesense::models::{CollectionSchema, DocumentValidator, Field};
+///
+/// let schema = CollectionSchema::builder()
+///     .name("books")
+///     .fields(vec![
+///         Field::builder().name("title").r#type("string").build(),
+///         Field::builder().name("ratings").r#type("int32[]").build(),
+///     ])
+///     .build();
+/// let validator = DocumentValidator::from(&schema);
+///
+/// assert!(validator.validate(&serde_json::json!({"title": "Dune", "ratings": [4, 5]})).is_ok());
+/// let errors = validator
+///     .validate(&serde_json::json!({"ratings": [4, "5"]}))
+///     .unwrap_err();
+/// assert_eq!(errors.len(), 2);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct DocumentValidator {
+    fields: Vec<Field>,
+}
+
+impl DocumentValidator {
+    /// Creates a validator for the given fields.
+    pub fn new(fields: Vec<Field>) -> Self {
+        let fields = fields
+            .into_iter()
+            .filter(|field| {
+                !field.name.contains('*')
+                    && field.embed.is_none()
+                    && !matches!(field.r#type.as_str(), "auto" | "string*")
+            })
+            .collect();
+        Self { fields }
+    }
+
+    /// Checks a complete document.
+    pub fn validate(&self, document: &Value) -> Result<(), Vec<DocumentValidationError>> {
+        self.check(document, false)
+    }
+
+    /// Checks a partial document, as sent with the `update` action.
+    ///
+    /// Missing fields are not reported; the fields that are present are checked.
+    pub fn validate_partial(&self, document: &Value) -> Result<(), Vec<DocumentValidationError>> {
+        self.check(document, true)
+    }
+
+    /// Checks a document as it is written with `action`, which defaults to `create`.
+    ///
+    /// Only `update` documents are checked with `validate_partial`, since `update` fails for a
+    /// missing document. All other actions, including `emplace`, may create the document, so it
+    /// is checked in full.
+    pub fn validate_for_action(
+        &self,
+        document: &Value,
+        action: Option<&IndexAction>,
+    ) -> Result<(), Vec<DocumentValidationError>> {
+        self.check(document, matches!(action, Some(IndexAction::Update)))
+    }
+
+    /// Checks every line of a JSONL import written with `action`, see `validate_for_action`.
+    ///
+    /// Empty lines are skipped. The valid lines are returned in their original order, ready to
+    /// be imported, and every invalid line with its errors.
+    ///
+    /// # Example
+    /// ```
+    /// use typesense::models::{DocumentValidator, Field};
+    ///
+    /// let validator = DocumentValidator::new(vec![
+    ///     Field::builder().name("title").r#type("string").build(),
+    /// ]);
+    /// let jsonl = "{\"title\":\"Dune\"}\n{\"title\":1965}";
+    /// let validation = validator.validate_jsonl(jsonl, None);
+    ///
+    /// assert_eq!(validation.valid_jsonl(), "{\"title\":\"Dune\"}");
+    /// assert_eq!(validation.invalid[0].line, 2);
+    /// ```
+    pub fn validate_jsonl<'a>(
+        &self,
+        jsonl: &'a str,
+        action: Option<&IndexAction>,
+    ) -> JsonlValidation<'a> {
+        let mut validation = JsonlValidation::default();
+        for (index, line) in jsonl.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let result = match serde_json::from_str(line) {
+                Ok(document) => self.validate_for_action(&document, action),
+                Err(err) => Err(vec![DocumentValidationError::InvalidJson(err.to_string())]),
+            };
+            match result {
+                Ok(()) => validation.valid.push(line),
+                Err(errors) => validation.invalid.push(InvalidJsonlLine {
+                    line: index + 1,
+                    document: line,
+                    errors,
+                }),
+            }
+        }
+        validation
+    }
+
+    /// Serializes a typed document and checks it with `validate`.
+    pub fn validate_document<D: Serialize>(
+        &self,
+        document: &D,
+    ) -> Result<(), Vec<DocumentValidationError>> {
+        let document = serde_json::to_value(document)
+            .map_err(|err| vec![DocumentValidationError::Serialization(err.to_string())])?;
+        self.validate(&document)
+    }
+
+    fn check(&self, document: &Value, partial: bool) -> Result<(), Vec<DocumentValidationError>> {
+        if !document.is_object() {
+            return Err(vec![DocumentValidationError::NotAnObject]);
+        }
+
+        let mut errors = Vec::new();
+        for field in &self.fields {
+            match lookup(document, &field.name) {
+                None | Some(Value::Null) => {
+                    if !partial && !field.optional.unwrap_or(false) {
+                        errors.push(DocumentValidationError::MissingField {
+                            field: field.name.clone(),
+                        });
+                    }
+                }
+                Some(value) => {
+                    if let Err(err) = check_value(field, &value) {
+                        errors.push(err);
+                    }
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+impl From<&CollectionSchema<'_>> for DocumentValidator {
+    fn from(schema: &CollectionSchema<'_>) -> Self {
+        Self::new(schema.fields.clone())
+    }
+}
+
+impl From<&CollectionResponse> for DocumentValidator {
+    fn from(collection: &CollectionResponse) -> Self {
+        Self::new(collection.fields.clone())
+    }
+}
+
+/// The lines of a JSONL import, split by `DocumentValidator::validate_jsonl`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct JsonlValidation<'a> {
+    /// The lines that passed the checks.
+    pub valid: Vec<&'a str>,
+    /// The lines that failed the checks.
+    pub invalid: Vec<InvalidJsonlLine<'a>>,
+}
+
+impl JsonlValidation<'_> {
+    /// The valid lines, joined to a JSONL string for an import.
+    pub fn valid_jsonl(&self) -> String {
+        self.valid.join("\n")
+    }
+}
+
+/// A line of a JSONL import that failed the checks of a `DocumentValidator`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct InvalidJsonlLine<'a> {
+    /// The 1-based number of the line in the input.
+    pub line: usize,
+    /// The line as it was given.
+    pub document: &'a str,
+    /// Everything that is wrong with the document.
+    pub errors: Vec<DocumentValidationError>,
+}
+
+/// Finds the value of a field, following dots into nested objects and arrays of objects.
+fn lookup(document: &Value, name: &str) -> Option<Value> {
+    if let Some(value) = document.get(name) {
+        return Some(value.clone());
+    }
+    let (head, rest) = name.split_once('.')?;
+    match document.get(head)? {
+        object @ Value::Object(_) => lookup(object, rest),
+        Value::Array(items) => Some(Value::Array(
+            items.iter().filter_map(|item| lookup(item, rest)).collect(),
+        )),
+        _ => None,
+    }
+}
+
+/// Describes a JSON value for error messages.
+fn describe(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "a boolean",
+        Value::Number(n) if n.is_f64() => "a floating point number",
+        Value::Number(_) => "an integer",
+        Value::String(_) => "a string",
+        Value::Array(_) => "an array",
+        Value::Object(_) => "an object",
+    }
+}
+
+fn check_value(field: &Field, value: &Value) -> Result<(), DocumentValidationError> {
+    let type_mismatch = |found: &Value| DocumentValidationError::TypeMismatch {
+        field: field.name.clone(),
+        expected: field.r#type.clone(),
+        found: describe(found),
+    };
+    let invalid_geo = |reason| DocumentValidationError::InvalidGeo {
+        field: field.name.clone(),
+        expected: field.r#type.clone(),
+        reason,
+    };
+
+    match field.r#type.as_str() {
+        "geopoint" => check_geopoint(value).map_err(invalid_geo),
+        "geopoint[]" => {
+            let points = value.as_array().ok_or_else(|| type_mismatch(value))?;
+            points
+                .iter()
+                .try_for_each(check_geopoint)
+                .map_err(invalid_geo)
+        }
+        "geopolygon" => check_geopolygon(value).map_err(invalid_geo),
+        r#type => match r#type.strip_suffix("[]") {
+            Some(element_type) => {
+                let items = value.as_array().ok_or_else(|| type_mismatch(value))?;
+                if let Some(item) = items.iter().find(|item| !is_scalar(element_type, item)) {
+                    return Err(type_mismatch(item));
+                }
+                if let Some(num_dim) = field.num_dim
+                    && items.len() != num_dim as usize
+                {
+                    return Err(DocumentValidationError::VectorDimensions {
+                        field: field.name.clone(),
+                        expected: num_dim as usize,
+                        found: items.len(),
+                    });
+                }
+                Ok(())
+            }
+            None if is_scalar(r#type, value) => Ok(()),
+            None => Err(type_mismatch(value)),
+        },
+    }
+}
+
+/// Whether `value` is a valid value of the non-array type `r#type`.
+/// Unknown types are accepted, so that newer server types don't cause false errors.
+fn is_scalar(r#type: &str, value: &Value) -> bool {
+    match r#type {
+        "string" | "image" => value.is_string(),
+        "int32" => value.as_i64().is_some_and(|n| i32::try_from(n).is_ok()),
+        "int64" => value.is_i64() || value.as_u64().is_some_and(|n| i64::try_from(n).is_ok()),
+        "float" => value.is_number(),
+        "bool" => value.is_boolean(),
+        "object" => value.is_object(),
+        _ => true,
+    }
+}
+
+/// Checks a `[latitude, longitude]` pair.
+fn check_geopoint(value: &Value) -> Result<(), &'static str> {
+    let coordinates = value
+        .as_array()
+        .filter(|c| c.len() == 2)
+        .ok_or("expected a [latitude, longitude] pair")?;
+    let (Some(lat), Some(lng)) = (coordinates[0].as_f64(), coordinates[1].as_f64()) else {
+        return Err("coordinates must be numbers");
+    };
+    check_coordinates(lat, lng)
+}
+
+/// Checks a flat `[lat1, lng1, lat2, lng2, ...]` polygon with at least three points.
+fn check_geopolygon(value: &Value) -> Result<(), &'static str> {
+    let coordinates = value
+        .as_array()
+        .ok_or("expected a flat array of coordinates")?
+        .iter()
+        .map(Value::as_f64)
+        .collect::<Option<Vec<_>>>()
+        .ok_or("coordinates must be numbers")?;
+    if coordinates.len() % 2 != 0 || coordinates.len() < 6 {
+        return Err("expected at least three [latitude, longitude] points");
+    }
+    coordinates
+        .chunks(2)
+        .try_for_each(|point| check_coordinates(point[0], point[1]))
+}
+
+fn check_coordinates(lat: f64, lng: f64) -> Result<(), &'static str> {
+    if !(-90.0..=90.0).contains(&lat) {
+        return Err("latitude must be between -90 and 90");
+    }
+    if !(-180.0..=180.0).contains(&lng) {
+        return Err("longitude must be between -180 and 180");
+    }
+    Ok(())
+}
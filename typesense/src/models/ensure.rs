@@ -0,0 +1,40 @@
+use crate::models::{ReindexOptions, ReindexReport, SchemaChangeWaitOptions, SchemaDiff};
+use typesense_codegen::models::CollectionResponse;
+
+/// What `Collection::ensure` does when the collection already exists.
+///
+/// A missing collection is always created from the schema derived for the document type.
+#[derive(Clone, Debug, Default)]
+pub enum EnsurePolicy {
+    /// Leave an existing collection as it is, without comparing schemas.
+    #[default]
+    CreateOnly,
+    /// Fail with `EnsureError::Drift` if the existing collection differs from the schema.
+    FailOnDrift,
+    /// Apply the differences with `Collection::update_and_wait`, so the collection has the new
+    /// schema once `ensure` returns.
+    /// Fails with `EnsureError::RequiresReindex` if they cannot be applied in place.
+    UpdateInPlace(SchemaChangeWaitOptions),
+    /// Re-index into a new, versioned collection and swap the alias, as `Alias::reindex` does.
+    ///
+    /// The collection must be accessed through an alias. When it does not exist yet, a versioned
+    /// collection named `{name}_{unix timestamp in milliseconds}` is created, together with an
+    /// alias `{name}` pointing to it. When it exists as a plain collection, `ensure` fails with
+    /// `EnsureError::NotAnAlias` before changing anything.
+    Reindex(ReindexOptions),
+}
+
+/// What `Collection::ensure` did.
+#[derive(Clone, Debug, PartialEq)]
+pub enum EnsureOutcome {
+    /// The collection did not exist and was created.
+    Created(CollectionResponse),
+    /// The collection already existed and was left as it is, without comparing schemas.
+    Exists,
+    /// The collection already existed and matches the schema.
+    UpToDate,
+    /// The collection was updated in place with the differences listed in the diff.
+    Updated(SchemaDiff),
+    /// The collection was re-indexed into a new collection, and the alias was swapped.
+    Reindexed(ReindexReport),
+}
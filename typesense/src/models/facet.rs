@@ -0,0 +1,272 @@
+use super::{FacetCounts, FacetableField, SortOrder, SortableField};
+use crate::FacetSpecError;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::{borrow::Cow, collections::HashMap, fmt};
+
+/// A `facet_by` list of fields.
+///
+/// Fields are added with `field`, which only takes `FacetableField`s, so a `FieldRef` of a
+/// field without `#[typesense(facet)]` is rejected at compile time. Fields with range buckets
+/// or a custom order of their values are added with `spec`. A `FacetBy` converts into the
+/// `Cow<str>` that the `facet_by` parameter takes.
+///
+/// # Example
+/// ```
+/// use typesense::models::{FacetBy, FacetSpec, SearchParameters, SortOrder};
+///
+/// let facet_by = FacetBy::new()
+///     .field("country")
+///     .spec(FacetSpec::new("industry").sort_alpha(SortOrder::Asc))
+///     .spec(
+///         FacetSpec::new("price")
+///             .range("cheap", 0.0, 10.0)?
+///             .range_from("expensive", 10.0)?,
+///     );
+/// assert_eq!(
+///     facet_by.to_string(),
+///     "country,industry(sort_by: _alpha:asc),price(cheap:[0, 10], expensive:[10, ])"
+/// );
+///
+/// let params = SearchParameters::builder().q("*").facet_by(facet_by).build();
+/// # Ok::<(), typesense::FacetSpecError>(())
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FacetBy {
+    specs: Vec<FacetSpec>,
+}
+
+impl FacetBy {
+    /// An empty list of facet fields.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a field to facet on.
+    pub fn field(self, field: impl FacetableField) -> Self {
+        self.spec(FacetSpec::new(field))
+    }
+
+    /// Adds a field to facet on, with range buckets or a custom order of its values.
+    pub fn spec(mut self, spec: FacetSpec) -> Self {
+        self.specs.push(spec);
+        self
+    }
+}
+
+impl fmt::Display for FacetBy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, spec) in self.specs.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            write!(f, "{spec}")?;
+        }
+        Ok(())
+    }
+}
+
+impl From<FacetBy> for String {
+    #[inline]
+    fn from(facet_by: FacetBy) -> Self {
+        facet_by.to_string()
+    }
+}
+
+impl<'a> From<FacetBy> for Cow<'a, str> {
+    #[inline]
+    fn from(facet_by: FacetBy) -> Self {
+        Cow::Owned(facet_by.to_string())
+    }
+}
+
+/// A single field of a `FacetBy`, with its options.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FacetSpec {
+    field: String,
+    ranges: Vec<(String, f64, Option<f64>)>,
+    sort_by: Option<String>,
+}
+
+impl FacetSpec {
+    /// Facets on `field`.
+    pub fn new(field: impl FacetableField) -> Self {
+        Self {
+            field: field.into_field_name(),
+            ranges: Vec::new(),
+            sort_by: None,
+        }
+    }
+
+    /// Counts the values from `min` (inclusive) to `max` (exclusive) under the facet value
+    /// `label`, instead of counting every value on its own.
+    ///
+    /// Typesense takes labels as they are, so a label must be made of ASCII letters, digits,
+    /// `_` and `-`. Any other label returns `FacetSpecError::InvalidRangeLabel`.
+    pub fn range(
+        self,
+        label: impl Into<String>,
+        min: f64,
+        max: f64,
+    ) -> Result<Self, FacetSpecError> {
+        self.push_range(label.into(), min, Some(max))
+    }
+
+    /// Counts the values from `min` (inclusive) upwards under the facet value `label`.
+    ///
+    /// The label is checked like that of `range`.
+    pub fn range_from(self, label: impl Into<String>, min: f64) -> Result<Self, FacetSpecError> {
+        self.push_range(label.into(), min, None)
+    }
+
+    fn push_range(
+        mut self,
+        label: String,
+        min: f64,
+        max: Option<f64>,
+    ) -> Result<Self, FacetSpecError> {
+        let valid = !label.is_empty()
+            && label
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+        if !valid {
+            return Err(FacetSpecError::InvalidRangeLabel(label));
+        }
+        self.ranges.push((label, min, max));
+        Ok(self)
+    }
+
+    /// Orders the facet values alphabetically, instead of by their counts.
+    pub fn sort_alpha(mut self, order: SortOrder) -> Self {
+        self.sort_by = Some(format!("_alpha:{order}"));
+        self
+    }
+
+    /// Orders the facet values by the value of `field` in the matching documents, instead of by
+    /// their counts.
+    pub fn sort_by_field(mut self, field: impl SortableField, order: SortOrder) -> Self {
+        self.sort_by = Some(format!("{}:{order}", field.into_field_name()));
+        self
+    }
+}
+
+impl fmt::Display for FacetSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut options = self
+            .ranges
+            .iter()
+            .map(|(label, min, max)| match max {
+                Some(max) => format!("{label}:[{min}, {max}]"),
+                None => format!("{label}:[{min}, ]"),
+            })
+            .collect::<Vec<_>>();
+        if let Some(sort_by) = &self.sort_by {
+            options.push(format!("sort_by: {sort_by}"));
+        }
+
+        if options.is_empty() {
+            f.write_str(&self.field)
+        } else {
+            write!(f, "{}({})", self.field, options.join(", "))
+        }
+    }
+}
+
+/// The facet counts of a single field of a search result, returned by `SearchResultExt::facet`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FacetResult {
+    /// The name of the faceted field.
+    pub field_name: String,
+    /// The most frequent values of the field among the matching documents, or the labels of the
+    /// ranges of a range facet, in the order Typesense returned them.
+    pub values: Vec<FacetValue>,
+    /// The number of distinct values of the field among the matching documents.
+    pub total_values: Option<i32>,
+    /// The statistics of a numeric field.
+    pub stats: Option<FacetStats>,
+    /// Whether the counts were estimated from a sample of the matching documents.
+    pub sampled: bool,
+}
+
+impl FacetResult {
+    /// The number of matching documents with the facet value `value`, if it was returned.
+    pub fn count(&self, value: &str) -> Option<u64> {
+        self.values
+            .iter()
+            .find(|facet_value| facet_value.value == value)
+            .map(|facet_value| facet_value.count)
+    }
+
+    /// The number of matching documents for each returned facet value.
+    pub fn counts(&self) -> HashMap<&str, u64> {
+        self.values
+            .iter()
+            .map(|facet_value| (facet_value.value.as_str(), facet_value.count))
+            .collect()
+    }
+}
+
+impl From<&FacetCounts> for FacetResult {
+    fn from(facet_counts: &FacetCounts) -> Self {
+        let values = facet_counts
+            .counts
+            .iter()
+            .flatten()
+            .map(|count| FacetValue {
+                value: count.value.clone().unwrap_or_default(),
+                count: count.count.map_or(0, |count| count.max(0) as u64),
+                highlighted: count.highlighted.clone(),
+                parent: count.parent.clone(),
+            })
+            .collect();
+        let stats = facet_counts.stats.as_deref();
+        Self {
+            field_name: facet_counts.field_name.clone().unwrap_or_default(),
+            values,
+            total_values: stats.and_then(|stats| stats.total_values),
+            stats: stats.and_then(|stats| {
+                Some(FacetStats {
+                    min: stats.min?,
+                    max: stats.max?,
+                    avg: stats.avg?,
+                    sum: stats.sum?,
+                })
+            }),
+            sampled: facet_counts.sampled.unwrap_or(false),
+        }
+    }
+}
+
+/// A facet value and the number of matching documents that have it.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FacetValue {
+    /// The value, or the label of a range.
+    pub value: String,
+    /// The number of matching documents with this value.
+    pub count: u64,
+    /// The value with the part that matched `facet_query` highlighted.
+    pub highlighted: Option<String>,
+    /// The object that contains the value, for fields listed in `facet_return_parent`.
+    pub parent: Option<Value>,
+}
+
+impl FacetValue {
+    /// Deserializes the `parent` object that contains the value into `T`.
+    pub fn parent_as<T: DeserializeOwned>(&self) -> Option<Result<T, serde_json::Error>> {
+        self.parent.clone().map(serde_json::from_value)
+    }
+}
+
+/// The statistics of the values of a numeric facet field among the matching documents.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct FacetStats {
+    /// The smallest value.
+    pub min: f64,
+    /// The largest value.
+    pub max: f64,
+    /// The average of the values.
+    pub avg: f64,
+    /// The sum of the values.
+    pub sum: f64,
+}
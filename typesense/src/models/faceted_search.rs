@@ -0,0 +1,260 @@
+use super::{FacetBy, FacetResult, Filter, MultiSearchBody, MultiSearchResult, SearchResult};
+use crate::{MultiSearchParseError, prelude::MultiSearchResultExt};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use typesense_codegen::models::MultiSearchCollectionParameters;
+
+/// The state of a faceted navigation: the query, the selected facet values and numeric ranges,
+/// and the page.
+///
+/// Values selected on a disjunctive facet are combined with OR, so the facet keeps showing the
+/// counts of its other values. This takes an extra search per refined disjunctive facet, which
+/// leaves out that facet's own refinements. `to_multi_search_body` builds all searches, and
+/// `merge_results` combines their results into one `FacetedSearchResult`.
+///
+/// # Example
+/// ```no_run
+/// # #[cfg(not(target_family = "wasm"))]
+/// # async fn example(client: typesense::Client) -> Result<(), Box<dyn std::error::Error>> {
+/// use serde_json::Value;
+/// use typesense::models::{FacetedSearchState, MultiSearchParameters};
+///
+/// let mut state = FacetedSearchState::builder()
+///     .collection("products")
+///     .query("shoe")
+///     .query_by("title")
+///     .disjunctive_facets(vec!["brand".to_owned(), "color".to_owned()])
+///     .conjunctive_facets(vec!["tags".to_owned()])
+///     .build();
+/// state.toggle_refinement("brand", "Acme");
+/// state.toggle_refinement("brand", "Globex");
+/// state.set_range("price", Some(10.0), None);
+///
+/// let response = client
+///     .multi_search()
+///     .perform(state.to_multi_search_body(), MultiSearchParameters::default())
+///     .await?;
+/// let result = state.merge_results::<Value>(&response)?;
+/// // Counts of all brands that match "shoe" with a price of at least 10.
+/// let brands = result.facet("brand");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, bon::Builder)]
+#[builder(on(String, into))]
+pub struct FacetedSearchState {
+    /// The collection to search in.
+    pub collection: String,
+    /// The query text.
+    #[builder(default = "*".to_owned())]
+    pub query: String,
+    /// The fields to search in, separated by commas.
+    pub query_by: Option<String>,
+    /// A filter that always applies, in addition to the refinements.
+    pub filter_by: Option<Filter>,
+    /// The order of the hits.
+    pub sort_by: Option<String>,
+    /// Facets whose selected values are combined with OR.
+    #[builder(default)]
+    pub disjunctive_facets: Vec<String>,
+    /// Facets whose selected values are combined with AND.
+    #[builder(default)]
+    pub conjunctive_facets: Vec<String>,
+    /// The selected values of each facet.
+    #[builder(default)]
+    pub refinements: BTreeMap<String, Vec<String>>,
+    /// The selected `(min, max)` range of each numeric field, both inclusive and optional.
+    #[builder(default)]
+    pub numeric_ranges: BTreeMap<String, (Option<f64>, Option<f64>)>,
+    /// The maximum number of values returned per facet.
+    pub max_facet_values: Option<i32>,
+    /// The page of hits, starting at 1.
+    #[builder(default = 1)]
+    pub page: i32,
+    /// The number of hits per page.
+    #[builder(default = 10)]
+    pub per_page: i32,
+}
+
+impl FacetedSearchState {
+    /// Selects `value` of `facet` if it is not selected, and deselects it otherwise.
+    /// Goes back to the first page.
+    pub fn toggle_refinement(&mut self, facet: &str, value: &str) {
+        let values = self.refinements.entry(facet.to_owned()).or_default();
+        match values.iter().position(|selected| selected == value) {
+            Some(i) => {
+                values.remove(i);
+            }
+            None => values.push(value.to_owned()),
+        }
+        if values.is_empty() {
+            self.refinements.remove(facet);
+        }
+        self.page = 1;
+    }
+
+    /// Whether `value` of `facet` is selected.
+    pub fn is_refined(&self, facet: &str, value: &str) -> bool {
+        self.refinements
+            .get(facet)
+            .is_some_and(|values| values.iter().any(|selected| selected == value))
+    }
+
+    /// Deselects all values of `facet`. Goes back to the first page.
+    pub fn clear_refinements(&mut self, facet: &str) {
+        self.refinements.remove(facet);
+        self.page = 1;
+    }
+
+    /// Limits the numeric `field` to the range from `min` to `max`, both inclusive and optional.
+    /// Goes back to the first page.
+    pub fn set_range(&mut self, field: &str, min: Option<f64>, max: Option<f64>) {
+        if min.is_none() && max.is_none() {
+            self.numeric_ranges.remove(field);
+        } else {
+            self.numeric_ranges.insert(field.to_owned(), (min, max));
+        }
+        self.page = 1;
+    }
+
+    /// The refined disjunctive facets, which each need a search of their own.
+    fn refined_disjunctive_facets(&self) -> impl Iterator<Item = &String> {
+        self.disjunctive_facets
+            .iter()
+            .filter(|facet| self.refinements.contains_key(*facet))
+    }
+
+    /// The filter of the base filter, the numeric ranges and the refinements of every facet
+    /// except `excluded`.
+    fn filter(&self, excluded: Option<&str>) -> Filter {
+        let refinements = self
+            .refinements
+            .iter()
+            .filter(|(facet, _)| Some(facet.as_str()) != excluded)
+            .map(|(facet, values)| {
+                if self.disjunctive_facets.contains(facet) {
+                    Filter::field(facet).one_of(values)
+                } else {
+                    Filter::all(values.iter().map(|value| Filter::field(facet).eq(value)))
+                }
+            });
+        let ranges = self
+            .numeric_ranges
+            .iter()
+            .map(|(field, range)| match *range {
+                (Some(min), Some(max)) => Filter::field(field).between(min, max),
+                (Some(min), None) => Filter::field(field).gte(min),
+                (None, Some(max)) => Filter::field(field).lte(max),
+                (None, None) => Filter::all([]),
+            });
+        Filter::all(
+            self.filter_by
+                .clone()
+                .into_iter()
+                .chain(ranges)
+                .chain(refinements),
+        )
+    }
+
+    fn search(
+        &self,
+        filter: Filter,
+        facet_by: FacetBy,
+    ) -> MultiSearchCollectionParameters<'static> {
+        let mut search = MultiSearchCollectionParameters::builder()
+            .collection(self.collection.clone())
+            .q(self.query.clone())
+            .facet_by(facet_by)
+            .build();
+        search.query_by = self.query_by.clone().map(Into::into);
+        search.max_facet_values = self.max_facet_values;
+        if !filter.is_empty() {
+            search.filter_by = Some(filter.into());
+        }
+        search
+    }
+
+    /// The searches for the current state: the search for the hits and the counts of the
+    /// unrefined and conjunctive facets, followed by one search per refined disjunctive facet.
+    pub fn to_multi_search_body(&self) -> MultiSearchBody<'static> {
+        let facet_by = self
+            .disjunctive_facets
+            .iter()
+            .chain(&self.conjunctive_facets)
+            .fold(FacetBy::new(), FacetBy::field);
+        let mut hits_search = self.search(self.filter(None), facet_by);
+        hits_search.sort_by = self.sort_by.clone().map(Into::into);
+        hits_search.page = Some(self.page);
+        hits_search.per_page = Some(self.per_page);
+
+        let facet_searches = self.refined_disjunctive_facets().map(|facet| {
+            let mut search = self.search(
+                self.filter(Some(facet)),
+                FacetBy::new().field(facet.as_str()),
+            );
+            search.per_page = Some(0);
+            search
+        });
+
+        MultiSearchBody {
+            searches: std::iter::once(hits_search).chain(facet_searches).collect(),
+        }
+    }
+
+    /// Combines the results of the searches of `to_multi_search_body` for the same state.
+    pub fn merge_results<D: DeserializeOwned>(
+        &self,
+        response: &MultiSearchResult<Value>,
+    ) -> Result<FacetedSearchResult<D>, MultiSearchParseError> {
+        let result = response.parse_at::<D>(0)?;
+        let mut facets = self
+            .disjunctive_facets
+            .iter()
+            .chain(&self.conjunctive_facets)
+            .filter_map(|facet| {
+                result
+                    .facet_counts
+                    .iter()
+                    .flatten()
+                    .find(|counts| counts.field_name.as_ref() == Some(facet))
+                    .map(FacetResult::from)
+            })
+            .collect::<Vec<_>>();
+
+        for (i, facet) in self.refined_disjunctive_facets().enumerate() {
+            let facet_result = response.parse_at::<Value>(i + 1)?;
+            let Some(counts) = facet_result
+                .facet_counts
+                .iter()
+                .flatten()
+                .find(|counts| counts.field_name.as_ref() == Some(facet))
+            else {
+                continue;
+            };
+            match facets.iter_mut().find(|f| &f.field_name == facet) {
+                Some(merged) => *merged = counts.into(),
+                None => facets.push(counts.into()),
+            }
+        }
+
+        Ok(FacetedSearchResult { result, facets })
+    }
+}
+
+/// The merged result of the searches of a `FacetedSearchState`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FacetedSearchResult<D> {
+    /// The result of the search for the hits.
+    pub result: SearchResult<D>,
+    /// The facet counts, where every refined disjunctive facet has the counts of its
+    /// own search.
+    pub facets: Vec<FacetResult>,
+}
+
+impl<D> FacetedSearchResult<D> {
+    /// The facet counts of `field`.
+    pub fn facet(&self, field: &str) -> Option<&FacetResult> {
+        self.facets.iter().find(|facet| facet.field_name == field)
+    }
+}
@@ -0,0 +1,207 @@
+use std::{borrow::Cow, fmt, hash, marker::PhantomData};
+
+/// Marks a capability that is enabled on a `FieldRef`.
+#[derive(Clone, Copy, Debug)]
+pub enum Enabled {}
+
+/// Marks a capability that is disabled on a `FieldRef`.
+#[derive(Clone, Copy, Debug)]
+pub enum Disabled {}
+
+/// Marks a field without an explicit `sort` attribute, which Typesense sorts on only when
+/// `is_sortable_by_default` holds for its type. Whether that is the case is decided by the Rust
+/// type `T` through `SortableByDefault`.
+pub struct DefaultSort<T: ?Sized>(PhantomData<fn() -> T>, Disabled);
+
+/// Whether Typesense sorts on a field of the given type without an explicit `sort` attribute.
+///
+/// Numeric, boolean and geopoint fields are sortable by default. Arrays and all other types
+/// are not.
+pub const fn is_sortable_by_default(r#type: &str) -> bool {
+    const TYPES: [&str; 5] = ["int32", "int64", "float", "bool", "geopoint"];
+
+    let r#type = r#type.as_bytes();
+    let mut i = 0;
+    'types: while i < TYPES.len() {
+        let candidate = TYPES[i].as_bytes();
+        i += 1;
+        if candidate.len() != r#type.len() {
+            continue;
+        }
+        let mut j = 0;
+        while j < candidate.len() {
+            if candidate[j] != r#type[j] {
+                continue 'types;
+            }
+            j += 1;
+        }
+        return true;
+    }
+    false
+}
+
+/// Rust types whose fields Typesense can sort on without `#[typesense(sort)]`.
+///
+/// Implemented for exactly the types whose `ToTypesenseField::to_typesense_type` is sortable
+/// by default according to `is_sortable_by_default`.
+#[diagnostic::on_unimplemented(
+    message = "fields of type `{Self}` are not sortable by default",
+    note = "add `#[typesense(sort)]` to the field to make it sortable"
+)]
+pub trait SortableByDefault {}
+
+macro_rules! impl_sortable_by_default {
+    ($($t:ty),*) => {
+        $(impl SortableByDefault for $t {})*
+    };
+}
+
+impl_sortable_by_default!(i8, u8, i16, u16, i32, u32, i64, u64, isize, usize, f32, f64);
+impl_sortable_by_default!(bool, super::GeoPoint);
+
+/// Stands in for the Rust type of a field declared with `#[typesense(type = "...")]`, where
+/// `SORTABLE` is `is_sortable_by_default` of the declared type.
+#[doc(hidden)]
+pub enum DeclaredType<const SORTABLE: bool> {}
+
+impl SortableByDefault for DeclaredType<true> {}
+
+/// A typed reference to a field of a collection, generated by `#[derive(Typesense)]` and
+/// accessed through `DocumentFields::fields`, e.g. `Company::fields().country`.
+///
+/// The type parameters record whether the field is faceted, sortable and indexed, so that
+/// builders can reject fields that don't support an operation at compile time: `FacetBy` only
+/// takes `FacetableField`s, `SortField` only `SortableField`s, and `Filter` and `QueryBy` only
+/// `IndexedField`s. Plain strings implement all three traits and are never checked.
+pub struct FieldRef<Facet = Disabled, Sort = Disabled, Index = Enabled> {
+    name: Cow<'static, str>,
+    flags: PhantomData<(Facet, Sort, Index)>,
+}
+
+impl<F, S, I> FieldRef<F, S, I> {
+    /// References the field `name`, e.g. `address.city` for a nested field.
+    pub fn new(name: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            name: name.into(),
+            flags: PhantomData,
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn prefixed(prefix: &str, name: &'static str) -> Self {
+        if prefix.is_empty() {
+            Self::new(name)
+        } else {
+            Self::new(format!("{prefix}{name}"))
+        }
+    }
+
+    /// The name of the field in the collection schema.
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+// Implemented by hand, as derives would require the markers to implement the traits as well.
+impl<F, S, I> Clone for FieldRef<F, S, I> {
+    fn clone(&self) -> Self {
+        Self::new(self.name.clone())
+    }
+}
+
+impl<F, S, I> fmt::Debug for FieldRef<F, S, I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("FieldRef").field(&self.name).finish()
+    }
+}
+
+impl<F, S, I> fmt::Display for FieldRef<F, S, I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.name)
+    }
+}
+
+impl<F, S, I> PartialEq for FieldRef<F, S, I> {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+    }
+}
+
+impl<F, S, I> Eq for FieldRef<F, S, I> {}
+
+impl<F, S, I> hash::Hash for FieldRef<F, S, I> {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+    }
+}
+
+/// Anything that names a collection field: a string or a `FieldRef`.
+pub trait FieldName {
+    /// The name of the field in the collection schema.
+    fn into_field_name(self) -> String;
+}
+
+/// A field that can be filtered and searched on, because it is indexed.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not an indexed field",
+    note = "remove `#[typesense(index = false)]` from the field to filter or search on it"
+)]
+pub trait IndexedField: FieldName {}
+
+/// A field that can be faceted on, because it has `facet: true`.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a facet field",
+    note = "add `#[typesense(facet)]` to the field to facet on it"
+)]
+pub trait FacetableField: FieldName {}
+
+/// A field that can be sorted on, because it has `sort: true` or its type is sortable by default.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a sortable field",
+    note = "add `#[typesense(sort)]` to the field to sort on it"
+)]
+pub trait SortableField: FieldName {}
+
+macro_rules! impl_field_name_for_strings {
+    ($($t:ty),*) => {
+        $(
+            impl FieldName for $t {
+                #[inline]
+                fn into_field_name(self) -> String {
+                    self.into()
+                }
+            }
+            impl IndexedField for $t {}
+            impl FacetableField for $t {}
+            impl SortableField for $t {}
+        )*
+    };
+}
+
+impl_field_name_for_strings!(&str, String, &String, Cow<'_, str>);
+
+impl<F, S, I> FieldName for FieldRef<F, S, I> {
+    #[inline]
+    fn into_field_name(self) -> String {
+        self.name.into_owned()
+    }
+}
+
+impl<F, S, I> FieldName for &FieldRef<F, S, I> {
+    #[inline]
+    fn into_field_name(self) -> String {
+        self.name.clone().into_owned()
+    }
+}
+
+impl<F, S> IndexedField for FieldRef<F, S, Enabled> {}
+impl<F, S> IndexedField for &FieldRef<F, S, Enabled> {}
+
+impl<S, I> FacetableField for FieldRef<Enabled, S, I> {}
+impl<S, I> FacetableField for &FieldRef<Enabled, S, I> {}
+
+impl<F, I> SortableField for FieldRef<F, Enabled, I> {}
+impl<F, I> SortableField for &FieldRef<F, Enabled, I> {}
+impl<F, T: SortableByDefault + ?Sized, I> SortableField for FieldRef<F, DefaultSort<T>, I> {}
+impl<F, T: SortableByDefault + ?Sized, I> SortableField for &FieldRef<F, DefaultSort<T>, I> {}
@@ -0,0 +1,381 @@
+use super::{GeoPoint, GeoPolygon, IndexedField};
+use std::{borrow::Cow, fmt};
+
+/// A value that can be compared against in a `Filter`.
+///
+/// Strings are wrapped in backticks, so commas, brackets, parentheses and `&&`/`||` inside
+/// them are taken literally. Backticks inside strings are escaped with a backslash.
+pub trait FilterValue {
+    /// Renders the value as it appears in a filter string.
+    fn to_filter_value(&self) -> String;
+}
+
+impl FilterValue for str {
+    fn to_filter_value(&self) -> String {
+        format!("`{}`", self.replace('`', "\\`"))
+    }
+}
+
+impl FilterValue for String {
+    #[inline]
+    fn to_filter_value(&self) -> String {
+        self.as_str().to_filter_value()
+    }
+}
+
+impl FilterValue for Cow<'_, str> {
+    #[inline]
+    fn to_filter_value(&self) -> String {
+        self.as_ref().to_filter_value()
+    }
+}
+
+impl<T: FilterValue + ?Sized> FilterValue for &T {
+    #[inline]
+    fn to_filter_value(&self) -> String {
+        (**self).to_filter_value()
+    }
+}
+
+macro_rules! impl_filter_value_display {
+    ($($t:ty),*) => {
+        $(
+            impl FilterValue for $t {
+                #[inline]
+                fn to_filter_value(&self) -> String {
+                    self.to_string()
+                }
+            }
+        )*
+    };
+}
+
+impl_filter_value_display!(
+    i8,
+    i16,
+    i32,
+    i64,
+    u8,
+    u16,
+    u32,
+    u64,
+    usize,
+    f32,
+    f64,
+    bool,
+    serde_json::Number
+);
+
+/// A distance for geo radius filters.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GeoDistance {
+    /// A distance in kilometers.
+    Km(f64),
+    /// A distance in miles.
+    Miles(f64),
+}
+
+impl fmt::Display for GeoDistance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GeoDistance::Km(km) => write!(f, "{km} km"),
+            GeoDistance::Miles(miles) => write!(f, "{miles} mi"),
+        }
+    }
+}
+
+/// A `filter_by` expression that renders to a correctly escaped Typesense filter string.
+///
+/// Conditions are created with `Filter::field` and combined with `and`/`or` (or `Filter::all`
+/// and `Filter::any`), which add parentheses where they are needed. A `Filter` converts into
+/// the `Cow<str>` that the `filter_by` parameters take, so it can be passed wherever a filter
+/// string is expected.
+///
+/// # Example
+/// ```
+/// use typesense::models::{Filter, GeoDistance, SearchParameters};
+///
+/// let filter = Filter::field("country")
+///     .one_of(["US", "Canada, eh"])
+///     .and(Filter::field("num_employees").between(10, 100))
+///     .and(
+///         Filter::field("location")
+///             .within_radius((48.85, 2.29), GeoDistance::Km(5.0))
+///             .or(Filter::field("remote").eq(true)),
+///     )
+///     .and(Filter::join("Products", Filter::field("price").lt(9.99)));
+/// assert_eq!(
+///     filter.to_string(),
+///     "country:=[`US`,`Canada, eh`] && num_employees:[10..100] && \
+///      (location:(48.85, 2.29, 5 km) || remote:=true) && $Products(price:<9.99)"
+/// );
+///
+/// let params = SearchParameters::builder().q("*").filter_by(filter).build();
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Filter(Expr);
+
+#[derive(Clone, Debug, PartialEq)]
+enum Expr {
+    Condition(String),
+    Raw(String),
+    And(Vec<Filter>),
+    Or(Vec<Filter>),
+    /// Matches every document, e.g. `none_of` an empty list. Renders as an empty filter.
+    Always,
+    /// Matches no document, e.g. `one_of` an empty list. Renders as `NEVER`.
+    Never,
+}
+
+/// A filter string that no document matches, since Typesense has no `false` literal.
+const NEVER: &str = "id:=`0` && id:!=`0`";
+
+impl Filter {
+    /// Starts a condition on a field.
+    #[inline]
+    pub fn field(name: impl IndexedField) -> FilterField {
+        FilterField {
+            name: name.into_field_name(),
+        }
+    }
+
+    /// Uses a filter string as is, without escaping.
+    ///
+    /// It is parenthesized when combined with other filters.
+    #[inline]
+    pub fn raw(filter: impl Into<String>) -> Self {
+        Self(Expr::Raw(filter.into()))
+    }
+
+    /// Matches documents that match all of `filters`. An empty list renders as an empty filter.
+    pub fn all(filters: impl IntoIterator<Item = Filter>) -> Self {
+        Self(Expr::And(filters.into_iter().collect()))
+    }
+
+    /// Matches documents that match any of `filters`. An empty list renders as an empty filter.
+    pub fn any(filters: impl IntoIterator<Item = Filter>) -> Self {
+        Self(Expr::Or(filters.into_iter().collect()))
+    }
+
+    /// Matches documents that reference a document of `collection` matching `filter`,
+    /// e.g. `$Customers(country:=US)`.
+    pub fn join(collection: &str, filter: Filter) -> Self {
+        Self(Expr::Condition(format!("${collection}({filter})")))
+    }
+
+    /// Matches documents that do not reference any document of `collection` matching `filter`,
+    /// e.g. `!$Customers(country:=US)`.
+    pub fn not_join(collection: &str, filter: Filter) -> Self {
+        Self(Expr::Condition(format!("!${collection}({filter})")))
+    }
+
+    /// Matches documents that match both this filter and `other`.
+    pub fn and(self, other: Filter) -> Self {
+        match self.0 {
+            Expr::And(mut filters) => {
+                filters.push(other);
+                Self(Expr::And(filters))
+            }
+            _ => Self(Expr::And(vec![self, other])),
+        }
+    }
+
+    /// Matches documents that match this filter, `other`, or both.
+    pub fn or(self, other: Filter) -> Self {
+        match self.0 {
+            Expr::Or(mut filters) => {
+                filters.push(other);
+                Self(Expr::Or(filters))
+            }
+            _ => Self(Expr::Or(vec![self, other])),
+        }
+    }
+
+    /// Whether the filter renders as an empty string.
+    pub fn is_empty(&self) -> bool {
+        match self.constant() {
+            Some(always) => always,
+            None => match &self.0 {
+                Expr::Condition(condition) | Expr::Raw(condition) => condition.is_empty(),
+                Expr::And(filters) | Expr::Or(filters) => filters.iter().all(Filter::is_empty),
+                Expr::Always | Expr::Never => unreachable!(),
+            },
+        }
+    }
+
+    /// Whether the filter matches every document (`Some(true)`) or none (`Some(false)`)
+    /// regardless of their values, because of an `Always` or `Never` in it.
+    fn constant(&self) -> Option<bool> {
+        let (filters, absorbing) = match &self.0 {
+            Expr::Always => return Some(true),
+            Expr::Never => return Some(false),
+            Expr::Condition(_) | Expr::Raw(_) => return None,
+            Expr::And(filters) => (filters, false),
+            Expr::Or(filters) => (filters, true),
+        };
+        let constants = filters.iter().map(Filter::constant).collect::<Vec<_>>();
+        if constants.contains(&Some(absorbing)) {
+            return Some(absorbing);
+        }
+        // What is left is `!absorbing` if every part is, apart from empty ones.
+        let identity = Some(!absorbing);
+        let reduces_to_identity = constants.contains(&identity)
+            && filters
+                .iter()
+                .zip(&constants)
+                .all(|(filter, constant)| *constant == identity || filter.is_empty());
+        reduces_to_identity.then_some(!absorbing)
+    }
+}
+
+impl fmt::Display for Filter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.constant() {
+            Some(true) => return Ok(()),
+            Some(false) => return f.write_str(NEVER),
+            None => {}
+        }
+        let (filters, separator) = match &self.0 {
+            Expr::Condition(condition) | Expr::Raw(condition) => return f.write_str(condition),
+            Expr::And(filters) => (filters, " && "),
+            Expr::Or(filters) => (filters, " || "),
+            Expr::Always | Expr::Never => unreachable!(),
+        };
+        // Parts that match every document are left out of `&&`, and parts that match no
+        // document are left out of `||`.
+        let filters = filters
+            .iter()
+            .filter(|filter| !filter.is_empty() && filter.constant().is_none())
+            .collect::<Vec<_>>();
+        if let [filter] = filters.as_slice() {
+            return filter.fmt(f);
+        }
+        for (i, filter) in filters.into_iter().enumerate() {
+            if i > 0 {
+                f.write_str(separator)?;
+            }
+            // Nested groups are always parenthesized, so their meaning never depends on
+            // operator precedence.
+            match &filter.0 {
+                Expr::Condition(_) => write!(f, "{filter}")?,
+                _ => write!(f, "({filter})")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl From<Filter> for String {
+    #[inline]
+    fn from(filter: Filter) -> Self {
+        filter.to_string()
+    }
+}
+
+impl<'a> From<Filter> for Cow<'a, str> {
+    #[inline]
+    fn from(filter: Filter) -> Self {
+        Cow::Owned(filter.to_string())
+    }
+}
+
+/// A field that a `Filter` condition is built on. Created with `Filter::field`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FilterField {
+    name: String,
+}
+
+impl FilterField {
+    fn condition(self, operator: &str, value: impl fmt::Display) -> Filter {
+        Filter(Expr::Condition(format!("{}:{operator}{value}", self.name)))
+    }
+
+    fn list<V: FilterValue>(values: impl IntoIterator<Item = V>) -> String {
+        let values = values
+            .into_iter()
+            .map(|value| value.to_filter_value())
+            .collect::<Vec<_>>();
+        format!("[{}]", values.join(","))
+    }
+
+    /// The field contains `value`: a word of a string field, or an element of an array field
+    /// (`field:value`).
+    pub fn matches(self, value: impl FilterValue) -> Filter {
+        self.condition("", value.to_filter_value())
+    }
+
+    /// The field is exactly `value` (`field:=value`).
+    pub fn eq(self, value: impl FilterValue) -> Filter {
+        self.condition("=", value.to_filter_value())
+    }
+
+    /// The field is not `value` (`field:!=value`).
+    pub fn ne(self, value: impl FilterValue) -> Filter {
+        self.condition("!=", value.to_filter_value())
+    }
+
+    /// The field is exactly one of `values` (`field:=[a,b]`).
+    ///
+    /// Typesense rejects an empty list, so without values the filter matches no document.
+    pub fn one_of<V: FilterValue>(self, values: impl IntoIterator<Item = V>) -> Filter {
+        let mut values = values.into_iter().peekable();
+        match values.peek() {
+            Some(_) => self.condition("=", Self::list(values)),
+            None => Filter(Expr::Never),
+        }
+    }
+
+    /// The field is none of `values` (`field:!=[a,b]`).
+    ///
+    /// Typesense rejects an empty list, so without values the filter matches every document
+    /// and renders as an empty filter.
+    pub fn none_of<V: FilterValue>(self, values: impl IntoIterator<Item = V>) -> Filter {
+        let mut values = values.into_iter().peekable();
+        match values.peek() {
+            Some(_) => self.condition("!=", Self::list(values)),
+            None => Filter(Expr::Always),
+        }
+    }
+
+    /// The field is greater than `value` (`field:>value`).
+    pub fn gt(self, value: impl FilterValue) -> Filter {
+        self.condition(">", value.to_filter_value())
+    }
+
+    /// The field is greater than or equal to `value` (`field:>=value`).
+    pub fn gte(self, value: impl FilterValue) -> Filter {
+        self.condition(">=", value.to_filter_value())
+    }
+
+    /// The field is less than `value` (`field:<value`).
+    pub fn lt(self, value: impl FilterValue) -> Filter {
+        self.condition("<", value.to_filter_value())
+    }
+
+    /// The field is less than or equal to `value` (`field:<=value`).
+    pub fn lte(self, value: impl FilterValue) -> Filter {
+        self.condition("<=", value.to_filter_value())
+    }
+
+    /// The field is between `min` and `max`, both inclusive (`field:[min..max]`).
+    pub fn between(self, min: impl FilterValue, max: impl FilterValue) -> Filter {
+        let range = format!("[{}..{}]", min.to_filter_value(), max.to_filter_value());
+        self.condition("", range)
+    }
+
+    /// The geopoint field is within `radius` of `center`.
+    pub fn within_radius(self, center: impl Into<GeoPoint>, radius: GeoDistance) -> Filter {
+        let GeoPoint { lat, lng } = center.into();
+        self.condition("", format!("({lat}, {lng}, {radius})"))
+    }
+
+    /// The geopoint field is inside `polygon`.
+    pub fn within_polygon(self, polygon: &GeoPolygon) -> Filter {
+        let vertices = polygon
+            .vertices()
+            .iter()
+            .map(|GeoPoint { lat, lng }| format!("{lat}, {lng}"))
+            .collect::<Vec<_>>();
+        self.condition("", format!("({})", vertices.join(", ")))
+    }
+}
@@ -0,0 +1,118 @@
+use crate::GeoPolygonError;
+use serde::{Deserialize, Serialize};
+
+/// A point on the globe, stored in a `geopoint` field.
+///
+/// It serializes to the `[latitude, longitude]` pair that Typesense expects, and is mapped to
+/// `geopoint` by `#[derive(Typesense)]`, or to `geopoint[]` as a `Vec<GeoPoint>`.
+///
+/// # Example
+/// ```
+/// use typesense::models::GeoPoint;
+///
+/// let eiffel_tower = GeoPoint::new(48.8583, 2.2945);
+/// assert_eq!(serde_json::to_string(&eiffel_tower).unwrap(), "[48.8583,2.2945]");
+/// assert_eq!(GeoPoint::from((48.8583, 2.2945)), eiffel_tower);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(from = "[f64; 2]", into = "[f64; 2]")]
+pub struct GeoPoint {
+    /// The latitude, from `-90.0` to `90.0`.
+    pub lat: f64,
+    /// The longitude, from `-180.0` to `180.0`.
+    pub lng: f64,
+}
+
+impl GeoPoint {
+    /// The point at `lat`, `lng`.
+    #[inline]
+    pub fn new(lat: f64, lng: f64) -> Self {
+        Self { lat, lng }
+    }
+}
+
+impl From<[f64; 2]> for GeoPoint {
+    #[inline]
+    fn from([lat, lng]: [f64; 2]) -> Self {
+        Self { lat, lng }
+    }
+}
+
+impl From<(f64, f64)> for GeoPoint {
+    #[inline]
+    fn from((lat, lng): (f64, f64)) -> Self {
+        Self { lat, lng }
+    }
+}
+
+impl From<GeoPoint> for [f64; 2] {
+    #[inline]
+    fn from(point: GeoPoint) -> Self {
+        [point.lat, point.lng]
+    }
+}
+
+/// An area on the globe, stored in a `geopolygon` field.
+///
+/// It has at least three vertices, and serializes to the flat
+/// `[lat1, lng1, lat2, lng2, ...]` array that Typesense expects.
+///
+/// # Example
+/// ```
+/// use typesense::models::{GeoPoint, GeoPolygon};
+///
+/// let area = GeoPolygon::new([(48.0, 2.0), (49.0, 2.0), (49.0, 3.0)]).unwrap();
+/// assert_eq!(area.vertices()[1], GeoPoint::new(49.0, 2.0));
+/// assert_eq!(serde_json::to_string(&area).unwrap(), "[48.0,2.0,49.0,2.0,49.0,3.0]");
+///
+/// assert!(GeoPolygon::new([(48.0, 2.0), (49.0, 2.0)]).is_err());
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "Vec<f64>", into = "Vec<f64>")]
+pub struct GeoPolygon {
+    vertices: Vec<GeoPoint>,
+}
+
+impl GeoPolygon {
+    /// The polygon with the given vertices, in order.
+    pub fn new(
+        vertices: impl IntoIterator<Item = impl Into<GeoPoint>>,
+    ) -> Result<Self, GeoPolygonError> {
+        let vertices = vertices.into_iter().map(Into::into).collect::<Vec<_>>();
+        if vertices.len() < 3 {
+            return Err(GeoPolygonError::TooFewVertices(vertices.len()));
+        }
+        Ok(Self { vertices })
+    }
+
+    /// The vertices of the polygon, in order.
+    #[inline]
+    pub fn vertices(&self) -> &[GeoPoint] {
+        &self.vertices
+    }
+}
+
+impl TryFrom<Vec<f64>> for GeoPolygon {
+    type Error = GeoPolygonError;
+
+    fn try_from(coordinates: Vec<f64>) -> Result<Self, Self::Error> {
+        if !coordinates.len().is_multiple_of(2) {
+            return Err(GeoPolygonError::UnpairedCoordinate);
+        }
+        Self::new(
+            coordinates
+                .chunks_exact(2)
+                .map(|point| GeoPoint::new(point[0], point[1])),
+        )
+    }
+}
+
+impl From<GeoPolygon> for Vec<f64> {
+    fn from(polygon: GeoPolygon) -> Self {
+        polygon
+            .vertices
+            .into_iter()
+            .flat_map(|point| [point.lat, point.lng])
+            .collect()
+    }
+}
@@ -0,0 +1,19 @@
+/// The result of `Documents::get_many`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetManyResult<D> {
+    /// The documents that were found, in the order their ids were requested.
+    ///
+    /// An id requested more than once yields a single document, at its first position.
+    pub documents: Vec<D>,
+    /// The requested ids for which no document exists, in the order they were requested.
+    pub missing: Vec<String>,
+}
+
+impl<D> Default for GetManyResult<D> {
+    fn default() -> Self {
+        Self {
+            documents: Vec::new(),
+            missing: Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,258 @@
+use super::SearchHighlight;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// The highlight of a single string in a hit, returned by `SearchResultHitExt::highlights`.
+///
+/// Fields of nested objects have a dotted `path` like `author.name`. Every element of an
+/// array field has its own highlight, with its position in `indices`; an array nested in
+/// another array has one index per array, from the outermost to the innermost.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FieldHighlight {
+    /// The dotted path of the field.
+    pub path: String,
+    /// The positions of the element in the array fields along the path.
+    pub indices: Vec<usize>,
+    /// The part of the field around the matches, with the matches marked by the highlight tags.
+    pub snippet: Option<String>,
+    /// The full field with the matches marked, only returned for `highlight_full_fields`.
+    pub value: Option<String>,
+    /// The tokens of the field that matched the query.
+    pub matched_tokens: Vec<String>,
+}
+
+impl FieldHighlight {
+    /// Collects the highlights of the `highlight` object of a hit, ordered by field.
+    pub(crate) fn from_highlight_map(highlight: &HashMap<String, Value>) -> Vec<Self> {
+        let mut fields = highlight.iter().collect::<Vec<_>>();
+        fields.sort_by_key(|(field, _)| *field);
+
+        let mut highlights = Vec::new();
+        for (field, value) in fields {
+            collect(value, field, &mut Vec::new(), &mut highlights);
+        }
+        highlights
+    }
+
+    /// Collects the highlights of the deprecated `highlights` list of a hit.
+    pub(crate) fn from_search_highlights(search_highlights: &[SearchHighlight]) -> Vec<Self> {
+        let mut highlights = Vec::new();
+        for highlight in search_highlights {
+            let path = highlight.field.clone().unwrap_or_default();
+            // Array fields have a list of matched tokens per snippet.
+            let tokens = |i: Option<usize>| {
+                let mut matched_tokens = Vec::new();
+                let tokens = highlight.matched_tokens.iter().flatten();
+                match i {
+                    Some(i) => tokens
+                        .skip(i)
+                        .take(1)
+                        .for_each(|t| strings(t, &mut matched_tokens)),
+                    None => tokens.for_each(|t| strings(t, &mut matched_tokens)),
+                }
+                matched_tokens
+            };
+            match &highlight.snippets {
+                Some(snippets) => {
+                    for (i, snippet) in snippets.iter().enumerate() {
+                        // `indices` lists the array positions of the snippets.
+                        let index = highlight
+                            .indices
+                            .as_ref()
+                            .and_then(|indices| indices.get(i))
+                            .map_or(i, |&index| index.max(0) as usize);
+                        highlights.push(Self {
+                            path: path.clone(),
+                            indices: vec![index],
+                            snippet: Some(snippet.clone()),
+                            value: highlight
+                                .values
+                                .as_ref()
+                                .and_then(|values| values.get(i).cloned()),
+                            matched_tokens: tokens(Some(i)),
+                        });
+                    }
+                }
+                None => highlights.push(Self {
+                    path,
+                    indices: Vec::new(),
+                    snippet: highlight.snippet.clone(),
+                    value: highlight.value.clone(),
+                    matched_tokens: tokens(None),
+                }),
+            }
+        }
+        highlights
+    }
+}
+
+/// Walks a value of the `highlight` object. Highlights of strings are objects with
+/// `matched_tokens`, anything else is a nested object or an array.
+fn collect(
+    value: &Value,
+    path: &str,
+    indices: &mut Vec<usize>,
+    highlights: &mut Vec<FieldHighlight>,
+) {
+    match value {
+        Value::Object(object) if object.contains_key("matched_tokens") => {
+            let mut matched_tokens = Vec::new();
+            strings(&object["matched_tokens"], &mut matched_tokens);
+            let string = |key: &str| object.get(key).and_then(Value::as_str).map(str::to_owned);
+            highlights.push(FieldHighlight {
+                path: path.to_owned(),
+                indices: indices.clone(),
+                snippet: string("snippet"),
+                value: string("value"),
+                matched_tokens,
+            });
+        }
+        Value::Object(object) => {
+            for (field, value) in object {
+                collect(value, &format!("{path}.{field}"), indices, highlights);
+            }
+        }
+        Value::Array(elements) => {
+            for (i, element) in elements.iter().enumerate() {
+                indices.push(i);
+                collect(element, path, indices, highlights);
+                indices.pop();
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Collects the strings of a string or a (nested) array of strings.
+fn strings(value: &Value, out: &mut Vec<String>) {
+    match value {
+        Value::String(string) => out.push(string.clone()),
+        Value::Array(values) => values.iter().for_each(|value| strings(value, out)),
+        _ => {}
+    }
+}
+
+/// A part of a highlighted string that either matched the query or not.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HighlightSegment<'a> {
+    /// The text of the segment, as stored in the document.
+    pub text: &'a str,
+    /// Whether the text matched the query.
+    pub matched: bool,
+}
+
+/// Renders highlighted snippets and values of a hit.
+///
+/// Typesense marks the matches with the `highlight_start_tag` and `highlight_end_tag` of the
+/// search, `<mark>` and `</mark>` by default, and doesn't escape the rest of the text. The
+/// renderer splits a highlighted string at those tags into `HighlightSegment`s, and renders
+/// them as HTML with every segment escaped and the matches wrapped in `start_tag` and `end_tag`.
+///
+/// # Example
+/// ```
+/// use typesense::models::{HighlightRenderer, HighlightSegment};
+///
+/// let renderer = HighlightRenderer::builder()
+///     .start_tag("<em class=\"hit\">")
+///     .end_tag("</em>")
+///     .build();
+/// let snippet = "<mark>Fish</mark> & <mark>Chips</mark> <b>today</b>";
+///
+/// assert_eq!(
+///     renderer.to_html(snippet),
+///     "<em class=\"hit\">Fish</em> &amp; <em class=\"hit\">Chips</em> &lt;b&gt;today&lt;/b&gt;"
+/// );
+/// assert_eq!(
+///     renderer.segments(snippet)[..2],
+///     [
+///         HighlightSegment { text: "Fish", matched: true },
+///         HighlightSegment { text: " & ", matched: false },
+///     ]
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, bon::Builder)]
+#[builder(on(String, into))]
+pub struct HighlightRenderer {
+    /// The `highlight_start_tag` of the search.
+    #[builder(default = "<mark>".to_owned())]
+    pub highlight_start_tag: String,
+    /// The `highlight_end_tag` of the search.
+    #[builder(default = "</mark>".to_owned())]
+    pub highlight_end_tag: String,
+    /// The HTML inserted before every match. It is not escaped.
+    #[builder(default = "<mark>".to_owned())]
+    pub start_tag: String,
+    /// The HTML inserted after every match. It is not escaped.
+    #[builder(default = "</mark>".to_owned())]
+    pub end_tag: String,
+}
+
+impl Default for HighlightRenderer {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+impl HighlightRenderer {
+    /// Splits a highlighted string into its matched and unmatched parts. Empty parts are left
+    /// out, and an unclosed match runs to the end of the string.
+    pub fn segments<'a>(&self, highlighted: &'a str) -> Vec<HighlightSegment<'a>> {
+        let mut segments = Vec::new();
+        let mut push = |text: &'a str, matched| {
+            if !text.is_empty() {
+                segments.push(HighlightSegment { text, matched });
+            }
+        };
+
+        let mut rest = highlighted;
+        if self.highlight_start_tag.is_empty() || self.highlight_end_tag.is_empty() {
+            push(rest, false);
+            return segments;
+        }
+        while let Some(start) = rest.find(&self.highlight_start_tag) {
+            push(&rest[..start], false);
+            rest = &rest[start + self.highlight_start_tag.len()..];
+            match rest.find(&self.highlight_end_tag) {
+                Some(end) => {
+                    push(&rest[..end], true);
+                    rest = &rest[end + self.highlight_end_tag.len()..];
+                }
+                None => {
+                    push(rest, true);
+                    rest = "";
+                }
+            }
+        }
+        push(rest, false);
+        segments
+    }
+
+    /// Renders a highlighted string as HTML-safe text, with the matches wrapped in `start_tag`
+    /// and `end_tag`.
+    pub fn to_html(&self, highlighted: &str) -> String {
+        let mut html = String::with_capacity(highlighted.len());
+        for segment in self.segments(highlighted) {
+            if segment.matched {
+                html.push_str(&self.start_tag);
+                escape_html(segment.text, &mut html);
+                html.push_str(&self.end_tag);
+            } else {
+                escape_html(segment.text, &mut html);
+            }
+        }
+        html
+    }
+}
+
+fn escape_html(text: &str, out: &mut String) {
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            c => out.push(c),
+        }
+    }
+}
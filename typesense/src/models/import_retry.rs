@@ -0,0 +1,190 @@
+use crate::models::DocumentValidator;
+use serde::{Deserialize, Serialize};
+use web_time::Duration;
+
+/// Whether a failed import line is worth submitting again.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportLineErrorKind {
+    /// A transient failure, e.g. a remote embedding timeout or a rate limit.
+    Retriable,
+    /// A failure that will not go away by retrying, e.g. a schema violation.
+    Permanent,
+}
+
+/// A single line of a JSONL import response.
+///
+/// Typesense answers every line of an import request with one of these, in the same order
+/// as the lines of the request.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ImportLineResponse {
+    /// Whether the document on this line was imported.
+    pub success: bool,
+    /// The error message for a failed line.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// The HTTP-like status code for a failed line, e.g. `400` or `409`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<u16>,
+    /// The original document of a failed line, as sent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub document: Option<String>,
+    /// The id of the imported document, when `return_id` is set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+}
+
+/// Counts the lines of one or more JSONL import responses.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ImportTally {
+    /// The number of lines that were submitted.
+    pub(crate) submitted: u64,
+    /// The number of lines that failed.
+    pub(crate) failed: u64,
+    /// The error message of the first failed line.
+    pub(crate) first_error: Option<String>,
+}
+
+impl ImportTally {
+    /// Adds the lines of an import response to the counts.
+    pub(crate) fn record(&mut self, response: &str) -> Result<(), serde_json::Error> {
+        for line in response.lines().filter(|line| !line.trim().is_empty()) {
+            let line: ImportLineResponse = serde_json::from_str(line)?;
+            self.submitted += 1;
+            if !line.success {
+                self.failed += 1;
+                if self.first_error.is_none() {
+                    self.first_error = line.error;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Classifies a failed import line using its status code and error message.
+///
+/// Rejected documents (`400`, `404`, `409` and `422`) are permanent failures. Server-side errors
+/// (`5xx`), request timeouts (`408`) and rate limits (`429`) are retriable. Lines without one of
+/// those codes are retriable only if the message is one Typesense uses for a transient condition,
+/// or a remote embedding error that describes a timeout, rate limit or unavailable service.
+/// Messages are matched as a whole or by their prefix, never by a bare word, since they can
+/// contain field names and values of the document.
+pub fn classify_import_error(code: Option<u16>, error: &str) -> ImportLineErrorKind {
+    const TRANSIENT_MESSAGES: [&str; 4] = [
+        "not ready or lagging",
+        "rate limit exceeded",
+        "too many requests",
+        "request timed out",
+    ];
+    const REMOTE_EMBEDDING_PREFIXES: [&str; 5] = [
+        "remote embedding",
+        "openai api error:",
+        "azure openai api error:",
+        "google api error:",
+        "gcp api error:",
+    ];
+    const REMOTE_TRANSIENT_PHRASES: [&str; 7] = [
+        "timed out",
+        "timeout",
+        "rate limit",
+        "too many requests",
+        "overloaded",
+        "unavailable",
+        "try again",
+    ];
+
+    match code {
+        Some(400 | 404 | 409 | 422) => return ImportLineErrorKind::Permanent,
+        Some(code) if code >= 500 || code == 408 || code == 429 => {
+            return ImportLineErrorKind::Retriable;
+        }
+        _ => {}
+    }
+
+    let error = error.trim().trim_end_matches('.').to_lowercase();
+    let is_transient = TRANSIENT_MESSAGES.contains(&error.as_str())
+        || REMOTE_EMBEDDING_PREFIXES.iter().any(|prefix| {
+            error.strip_prefix(prefix).is_some_and(|remote| {
+                REMOTE_TRANSIENT_PHRASES
+                    .iter()
+                    .any(|phrase| remote.contains(phrase))
+            })
+        });
+    if is_transient {
+        ImportLineErrorKind::Retriable
+    } else {
+        ImportLineErrorKind::Permanent
+    }
+}
+
+/// Options for `Documents::import_jsonl_with_retry`.
+///
+/// Retriable lines are re-submitted with exponential backoff: the first retry waits
+/// `initial_backoff`, and every following one waits twice as long, capped at `max_backoff`.
+#[derive(Clone, Debug, bon::Builder)]
+pub struct ImportRetryOptions {
+    /// How many times retriable lines are re-submitted before they are dead-lettered.
+    #[builder(default = 3)]
+    pub max_retries: u32,
+    /// The delay before the first retry.
+    #[builder(default = Duration::from_millis(500))]
+    pub initial_backoff: Duration,
+    /// The upper bound for the delay between retries.
+    #[builder(default = Duration::from_secs(30))]
+    pub max_backoff: Duration,
+    /// Decides whether a failed line is retriable. Defaults to [`classify_import_error`].
+    #[builder(default = classify_import_error)]
+    pub classifier: fn(Option<u16>, &str) -> ImportLineErrorKind,
+    /// Checks every line locally before the first request. Invalid lines are not sent, but
+    /// handed to the dead-letter sink right away, as `Permanent` failures with 0 attempts.
+    ///
+    /// Lines imported with the `update` action are checked as partial documents, see
+    /// `DocumentValidator::validate_for_action`.
+    pub validator: Option<DocumentValidator>,
+}
+
+impl Default for ImportRetryOptions {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+impl ImportRetryOptions {
+    /// The delay before retry number `attempt` (starting at 1).
+    pub(crate) fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+}
+
+/// An import line that could not be imported, as handed to a `DeadLetterSink`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FailedImportLine {
+    /// The JSONL line as it was submitted.
+    pub document: String,
+    /// The last error message returned by Typesense for this line.
+    pub error: String,
+    /// The last status code returned by Typesense for this line, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<u16>,
+    /// How the last error was classified. `Retriable` means the retries were exhausted.
+    pub kind: ImportLineErrorKind,
+    /// How many times the line was submitted. `0` for lines rejected by the validator.
+    pub attempts: u32,
+}
+
+/// Summary of an import performed with `Documents::import_jsonl_with_retry`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ImportReport {
+    /// Lines that were eventually imported.
+    pub imported: usize,
+    /// Lines that were imported after at least one retry.
+    pub recovered: usize,
+    /// Lines handed to the dead-letter sink.
+    pub dead_lettered: usize,
+    /// How many import requests were sent.
+    pub requests: u32,
+}
@@ -0,0 +1,155 @@
+use crate::models::ApiStatsResponse;
+use std::ops::Range;
+use web_time::Duration;
+
+/// Options for `Documents::import_jsonl_throttled`.
+///
+/// The import starts with `initial_batch_size` and `initial_concurrency`. After every round of
+/// concurrent batches, the server's API stats are polled: when `pending_write_batches` or
+/// `write_latency_ms` exceed their limits, batch size and concurrency are halved. Once both are
+/// back under half of their limits, they grow again, up to `max_batch_size` and `max_concurrency`:
+/// the batch size by half (at least by one), and the concurrency by one.
+#[derive(Clone, Debug, bon::Builder)]
+pub struct ImportThrottleOptions {
+    /// The number of documents sent in each import request at the start.
+    #[builder(default = 1_000)]
+    pub initial_batch_size: usize,
+    /// The smallest batch size the throttle shrinks to.
+    #[builder(default = 50)]
+    pub min_batch_size: usize,
+    /// The largest batch size the throttle grows to.
+    #[builder(default = 10_000)]
+    pub max_batch_size: usize,
+    /// The number of import requests in flight at the start.
+    #[builder(default = 2)]
+    pub initial_concurrency: usize,
+    /// The largest number of import requests in flight.
+    #[builder(default = 8)]
+    pub max_concurrency: usize,
+    /// The `pending_write_batches` value above which the server is considered backed up.
+    #[builder(default = 10.0)]
+    pub max_pending_write_batches: f64,
+    /// The `write_latency_ms` value above which the server is considered backed up.
+    #[builder(default = 1_000.0)]
+    pub max_write_latency_ms: f64,
+    /// How long to pause before the next round when the server is backed up.
+    #[builder(default = Duration::from_secs(1))]
+    pub cooldown: Duration,
+}
+
+impl Default for ImportThrottleOptions {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+/// What the throttle concluded from a set of API stats.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ThrottleSignal {
+    /// The server is backed up; batch size and concurrency were reduced.
+    Backpressure,
+    /// The server has spare capacity; batch size and concurrency were increased.
+    Recovered,
+    /// Nothing changed.
+    Steady,
+}
+
+/// Adjusts import batch size and concurrency based on server backpressure.
+///
+/// Used by `Documents::import_jsonl_throttled`, and usable on its own to drive a custom
+/// import loop: feed it the result of `client.operations().retrieve_api_stats()` between
+/// batches and read back `batch_size()` and `concurrency()`.
+#[derive(Clone, Debug)]
+pub struct AdaptiveThrottle {
+    options: ImportThrottleOptions,
+    batch_size: usize,
+    concurrency: usize,
+}
+
+impl AdaptiveThrottle {
+    /// Creates a new `AdaptiveThrottle` starting at the initial batch size and concurrency.
+    pub fn new(options: ImportThrottleOptions) -> Self {
+        let batch_size = options
+            .initial_batch_size
+            .clamp(options.min_batch_size.max(1), options.max_batch_size.max(1));
+        let concurrency = options
+            .initial_concurrency
+            .clamp(1, options.max_concurrency.max(1));
+        Self {
+            options,
+            batch_size,
+            concurrency,
+        }
+    }
+
+    /// The number of documents to send in the next import request.
+    #[inline]
+    pub fn batch_size(&self) -> usize {
+        self.batch_size
+    }
+
+    /// The number of import requests to keep in flight.
+    #[inline]
+    pub fn concurrency(&self) -> usize {
+        self.concurrency
+    }
+
+    /// The options this throttle was created with.
+    #[inline]
+    pub fn options(&self) -> &ImportThrottleOptions {
+        &self.options
+    }
+
+    /// Updates batch size and concurrency from the server's API stats.
+    ///
+    /// Missing stats are treated as healthy.
+    pub fn observe(&mut self, stats: &ApiStatsResponse) -> ThrottleSignal {
+        let pending = stats.pending_write_batches.unwrap_or(0.0);
+        let latency = stats.write_latency_ms.unwrap_or(0.0);
+        let options = &self.options;
+
+        if pending > options.max_pending_write_batches || latency > options.max_write_latency_ms {
+            // Even at the floor, the caller should still back off before the next round.
+            self.batch_size = (self.batch_size / 2).max(options.min_batch_size.max(1));
+            self.concurrency = (self.concurrency / 2).max(1);
+            return ThrottleSignal::Backpressure;
+        }
+
+        if pending <= options.max_pending_write_batches / 2.0
+            && latency <= options.max_write_latency_ms / 2.0
+        {
+            // Grows by at least one, so a batch size of 1 can recover.
+            let growth = (self.batch_size / 2).max(1);
+            let batch_size = (self.batch_size + growth).min(options.max_batch_size);
+            let concurrency = (self.concurrency + 1).min(options.max_concurrency.max(1));
+            if (batch_size, concurrency) != (self.batch_size, self.concurrency) {
+                self.batch_size = batch_size;
+                self.concurrency = concurrency;
+                return ThrottleSignal::Recovered;
+            }
+        }
+
+        ThrottleSignal::Steady
+    }
+}
+
+/// Summary of an import performed with `Documents::import_jsonl_throttled`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ThrottledImportReport {
+    /// The JSONL import responses of the batches that succeeded, one line per document, in the
+    /// same order as the input.
+    pub response: String,
+    /// How many import requests were sent.
+    pub batches: usize,
+    /// The number of non-empty input lines that were sent. Lines after them were not imported.
+    pub submitted_lines: usize,
+    /// The ranges of non-empty input lines whose import request failed as a whole. Their
+    /// documents have no line in `response`.
+    pub failed_batches: Vec<Range<usize>>,
+    /// How many times the server reported backpressure.
+    pub throttled: usize,
+    /// The batch size in use when the import finished.
+    pub final_batch_size: usize,
+    /// The concurrency in use when the import finished.
+    pub final_concurrency: usize,
+}
@@ -0,0 +1,156 @@
+use crate::traits::{Document, JoinedDocument};
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de, ser};
+use serde_json::Value;
+
+/// A document with the document(s) of another collection that a `$collection(...)` include
+/// embedded in it.
+///
+/// Typesense nests the joined document under the name of its collection. `Joined<D, R>`
+/// deserializes the document `D` from the hit, and the joined `R` from that nested object. `R`
+/// is a single document, a `Vec` of them when several documents reference the hit, or an
+/// `Option` for left joins. The two document types must be related by a
+/// `#[typesense(reference = "...")]` field on either of them, which is checked when the code is
+/// built (though not by `cargo check`).
+///
+/// # Example
+/// ```no_run
+/// # #[cfg(not(target_family = "wasm"))]
+/// # async fn example(client: typesense::Client) -> Result<(), Box<dyn std::error::Error>> {
+/// use serde::{Deserialize, Serialize};
+/// use typesense::{Typesense, models::{Joined, SearchParameters}, prelude::*};
+///
+/// #[derive(Typesense, Serialize, Deserialize)]
+/// #[typesense(collection_name = "customers")]
+/// struct Customer {
+///     id: String,
+///     name: String,
+/// }
+///
+/// #[derive(Typesense, Serialize, Deserialize)]
+/// #[typesense(collection_name = "orders")]
+/// struct Order {
+///     total: f64,
+///     #[typesense(reference = "customers.id")]
+///     customer_id: String,
+/// }
+///
+/// let params = SearchParameters::builder()
+///     .q("*")
+///     .include_fields(Joined::<Order, Customer>::include_fields())
+///     .build();
+/// let result = client
+///     .collection_named::<Joined<Order, Customer>>(Order::COLLECTION_NAME)
+///     .documents()
+///     .search(params)
+///     .await?;
+/// for hit in result.hits.into_iter().flatten() {
+///     let order = hit.document.unwrap();
+///     println!("{} ordered {}", order.joined.name, order.document.total);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Documents that are not related by a reference cannot be joined:
+/// ```compile_fail
+/// # use serde::{Deserialize, Serialize};
+/// # use typesense::{Typesense, models::Joined};
+/// #[derive(Typesense, Serialize, Deserialize)]
+/// #[typesense(collection_name = "customers")]
+/// struct Customer {
+///     name: String,
+/// }
+///
+/// #[derive(Typesense, Serialize, Deserialize)]
+/// #[typesense(collection_name = "products")]
+/// struct Product {
+///     title: String,
+/// }
+///
+/// let hit = serde_json::json!({ "title": "Shoe", "customers": { "name": "Ada" } });
+/// let joined: Joined<Product, Customer> = serde_json::from_value(hit).unwrap();
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Joined<D, R> {
+    /// The document of the searched collection.
+    pub document: D,
+    /// The embedded document(s) of the joined collection.
+    pub joined: R,
+}
+
+impl<D: Document, R: JoinedDocument> Joined<D, R> {
+    /// The name of the joined collection, under which Typesense nests the joined document(s).
+    const JOINED_COLLECTION: &'static str = {
+        assert!(
+            is_related::<D, R::Document>(),
+            "the documents of a `Joined` must be related by a `reference` field"
+        );
+        <R::Document as Document>::COLLECTION_NAME
+    };
+
+    /// The `include_fields` that embeds all fields of the joined collection, e.g.
+    /// `$customers(*)`.
+    pub fn include_fields() -> String {
+        format!("${}(*)", Self::JOINED_COLLECTION)
+    }
+}
+
+impl<'de, D: Document, R: JoinedDocument> Deserialize<'de> for Joined<D, R> {
+    fn deserialize<De: Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error> {
+        let collection = Self::JOINED_COLLECTION;
+        let mut object = serde_json::Map::deserialize(deserializer)?;
+        let joined = object.remove(collection).unwrap_or(Value::Null);
+        Ok(Self {
+            joined: R::deserialize(joined).map_err(|e| {
+                de::Error::custom(format_args!("invalid joined `{collection}`: {e}"))
+            })?,
+            document: D::deserialize(Value::Object(object)).map_err(de::Error::custom)?,
+        })
+    }
+}
+
+impl<D: Document, R: JoinedDocument> Serialize for Joined<D, R> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut document = serde_json::to_value(&self.document).map_err(ser::Error::custom)?;
+        let joined = serde_json::to_value(&self.joined).map_err(ser::Error::custom)?;
+        match document.as_object_mut() {
+            Some(object) => {
+                object.insert(Self::JOINED_COLLECTION.to_owned(), joined);
+            }
+            None => return Err(ser::Error::custom("a joined document must be an object")),
+        }
+        document.serialize(serializer)
+    }
+}
+
+/// Whether either document has a `reference` field that points to the other's collection.
+const fn is_related<D: Document, R: Document>() -> bool {
+    references(D::REFERENCED_COLLECTIONS, R::COLLECTION_NAME)
+        || references(R::REFERENCED_COLLECTIONS, D::COLLECTION_NAME)
+}
+
+const fn references(collections: &[&str], collection: &str) -> bool {
+    let mut i = 0;
+    while i < collections.len() {
+        if str_eq(collections[i], collection) {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+const fn str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
@@ -1,13 +1,61 @@
 //! # Typesense generic models
+mod collection_clone;
+#[cfg(feature = "csv")]
+mod csv_import;
 mod document_index_parameters;
+mod document_retrieve_parameters;
+mod document_validator;
+mod ensure;
+mod facet;
+mod faceted_search;
+mod field_ref;
+mod filter;
+mod geo;
+mod get_many;
+mod highlight;
+mod import_retry;
+mod import_throttle;
+mod joined;
 mod multi_search;
+mod query_by;
+mod reindex;
+mod schema_change_wait;
+mod schema_diff;
 mod scoped_key_parameters;
+mod scroll;
+mod search_pages;
+mod sort;
+mod vector_query;
 
+pub use collection_clone::*;
+#[cfg(feature = "csv")]
+pub use csv_import::*;
 pub use document_index_parameters::*;
+pub use document_retrieve_parameters::*;
+pub use document_validator::*;
+pub use ensure::*;
+pub use facet::*;
+pub use faceted_search::*;
+pub use field_ref::*;
+pub use filter::*;
+pub use geo::*;
+pub use get_many::*;
+pub use highlight::*;
+pub use import_retry::*;
+pub use import_throttle::*;
+pub use joined::*;
+pub use query_by::*;
+pub use reindex::*;
+pub use schema_change_wait::*;
+pub use schema_diff::*;
 pub use scoped_key_parameters::*;
+pub use scroll::*;
+pub use search_pages::*;
+pub use sort::*;
 pub use typesense_codegen::{
     apis::{analytics_api::GetAnalyticsEventsParams, operations_api::TakeSnapshotParams},
     models::{curation_rule::Match as CurationRuleMatch, *},
 };
+pub use vector_query::*;
 
 pub use multi_search::MultiSearchBody;
@@ -0,0 +1,56 @@
+use super::IndexedField;
+use std::{borrow::Cow, fmt};
+
+/// A `query_by` list of fields to search in, from the most to the least important.
+///
+/// Fields are added with `field`, which only takes `IndexedField`s, so a `FieldRef` of a field
+/// with `#[typesense(index = false)]` is rejected at compile time. A `QueryBy` converts into the
+/// `Cow<str>` that the `query_by` parameter takes.
+///
+/// # Example
+/// ```
+/// use typesense::models::{QueryBy, SearchParameters};
+///
+/// let query_by = QueryBy::new().field("company_name").field("country");
+/// assert_eq!(query_by.to_string(), "company_name,country");
+///
+/// let params = SearchParameters::builder().q("acme").query_by(query_by).build();
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct QueryBy {
+    fields: Vec<String>,
+}
+
+impl QueryBy {
+    /// An empty list of fields.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a field to search in.
+    pub fn field(mut self, field: impl IndexedField) -> Self {
+        self.fields.push(field.into_field_name());
+        self
+    }
+}
+
+impl fmt::Display for QueryBy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.fields.join(","))
+    }
+}
+
+impl From<QueryBy> for String {
+    #[inline]
+    fn from(query_by: QueryBy) -> Self {
+        query_by.to_string()
+    }
+}
+
+impl<'a> From<QueryBy> for Cow<'a, str> {
+    #[inline]
+    fn from(query_by: QueryBy) -> Self {
+        Cow::Owned(query_by.to_string())
+    }
+}
@@ -0,0 +1,62 @@
+use typesense_codegen::models::{
+    ExportDocumentsParameters, ImportDocumentsParameters, IndexAction,
+};
+
+/// Where `Alias::reindex` takes the documents for the new collection from.
+pub enum ReindexSource<'a, S = futures::stream::Empty<String>> {
+    /// Export the documents of the collection the alias currently points to.
+    ///
+    /// The parameters can narrow the export, e.g. with `exclude_fields` for fields that the
+    /// new schema no longer has.
+    Export(ExportDocumentsParameters<'a>),
+    /// Documents provided by the caller, as a stream of JSONL chunks.
+    ///
+    /// Every chunk is sent as its own import request.
+    Stream(S),
+}
+
+impl<'a> ReindexSource<'a> {
+    /// Export all documents of the collection the alias currently points to.
+    #[inline]
+    pub fn export() -> Self {
+        Self::Export(ExportDocumentsParameters::default())
+    }
+}
+
+/// Options for `Alias::reindex`.
+#[derive(Clone, Debug, bon::Builder)]
+pub struct ReindexOptions {
+    /// Keep the previous collection after the alias was swapped, so it can be rolled back to.
+    #[builder(default)]
+    pub keep_old_collection: bool,
+    /// The number of exported documents sent in each import request.
+    /// Only used with `ReindexSource::Export`. The export is streamed, so at most one batch
+    /// of documents is held in memory at a time.
+    #[builder(default = 1_000)]
+    pub batch_size: usize,
+    /// The parameters of every import request into the new collection.
+    #[builder(default = ImportDocumentsParameters {
+        action: Some(IndexAction::Create),
+        ..Default::default()
+    })]
+    pub import_parameters: ImportDocumentsParameters,
+}
+
+impl Default for ReindexOptions {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+/// Summary of a successful `Alias::reindex`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ReindexReport {
+    /// The collection the alias pointed to before, if the alias existed.
+    pub old_collection: Option<String>,
+    /// The collection the alias points to now.
+    pub new_collection: String,
+    /// The number of documents indexed into the new collection.
+    pub documents: u64,
+    /// Whether the old collection was deleted after the swap.
+    pub old_collection_deleted: bool,
+}
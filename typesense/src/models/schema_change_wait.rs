@@ -0,0 +1,18 @@
+use web_time::Duration;
+
+/// Options for `Collection::wait_for_schema_changes` and `Collection::update_and_wait`.
+#[derive(Clone, Debug, bon::Builder)]
+pub struct SchemaChangeWaitOptions {
+    /// How long to wait for the alter to finish before giving up.
+    #[builder(default = Duration::from_secs(600))]
+    pub timeout: Duration,
+    /// How long to pause between two polls of the schema change status.
+    #[builder(default = Duration::from_secs(1))]
+    pub poll_interval: Duration,
+}
+
+impl Default for SchemaChangeWaitOptions {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
@@ -0,0 +1,323 @@
+use super::is_sortable_by_default;
+use typesense_codegen::models::{
+    CollectionResponse, CollectionSchema, CollectionUpdateSchema, Field,
+};
+
+/// An attribute of a collection field that is compared by `SchemaDiff`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FieldAttribute {
+    /// The field's `type`, e.g. `string` or `int32[]`.
+    Type,
+    /// Whether the field can be faceted on.
+    Facet,
+    /// Whether the field can be sorted on.
+    Sort,
+    /// Whether the field is indexed.
+    Index,
+    /// Whether the field may be missing from documents.
+    Optional,
+    /// Whether infix search is enabled on the field.
+    Infix,
+    /// The locale of the field's tokenizer.
+    Locale,
+    /// Whether the field's values are stemmed.
+    Stem,
+    /// Whether the field's values are stored on disk.
+    Store,
+    /// The `collection.field` the field references.
+    Reference,
+    /// The number of dimensions of a vector field.
+    NumDim,
+    /// The source fields and model of an auto-embedding field.
+    Embed,
+}
+
+/// A single attribute that differs between the live field and the desired field.
+///
+/// Unset attributes are compared by their server-side defaults, so both values are
+/// rendered as they take effect.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldAttributeChange {
+    /// The attribute that differs.
+    pub attribute: FieldAttribute,
+    /// The value of the attribute in the live collection.
+    pub live: String,
+    /// The value of the attribute in the desired schema.
+    pub desired: String,
+}
+
+/// A field that exists in both schemas, but with different attributes.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FieldChange {
+    /// The field as it exists in the live collection.
+    pub live: Field,
+    /// The field as it is described by the desired schema.
+    pub desired: Field,
+    /// The attributes that differ.
+    pub changes: Vec<FieldAttributeChange>,
+}
+
+/// Why a `SchemaDiff` cannot be applied in place with `Collection::update`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ReindexReason {
+    /// A collection-level setting changed, e.g. `default_sorting_field` or `token_separators`.
+    /// These can only be set when a collection is created.
+    CollectionSetting(&'static str),
+    /// The type of a field changed. The stored documents still hold values of the old type,
+    /// which re-adding the field validates against the new type.
+    TypeChanged(String),
+    /// A changed field is not stored on disk, or its `store` setting changed, so its values
+    /// cannot be re-read when the field is re-added.
+    UnstoredField(String),
+    /// The number of dimensions or the embedding of a vector field changed. The stored vectors
+    /// do not fit the new definition, and an alter cannot compute new ones.
+    VectorChanged(String),
+}
+
+/// The differences between a live collection and a desired collection schema.
+///
+/// Created with `SchemaDiff::between` or `Collection::schema_diff`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SchemaDiff {
+    /// Fields of the desired schema that the live collection does not have.
+    pub added: Vec<Field>,
+    /// Fields of the live collection that the desired schema does not have.
+    pub removed: Vec<Field>,
+    /// Fields whose attributes differ.
+    pub changed: Vec<FieldChange>,
+    /// The desired synonym sets, if they differ from the live ones.
+    pub synonym_sets: Option<Vec<String>>,
+    /// The reasons why the differences cannot be applied in place. Empty if they can.
+    pub reindex_reasons: Vec<ReindexReason>,
+}
+
+impl SchemaDiff {
+    /// Compares a live collection with the desired schema.
+    ///
+    /// Attributes that are unset in either schema are compared by their server-side defaults.
+    /// The implicit `id` field, and sub-fields that Typesense detected inside nested `object`
+    /// fields, are ignored.
+    pub fn between(live: &CollectionResponse, desired: &CollectionSchema<'_>) -> Self {
+        let mut diff = SchemaDiff::default();
+
+        for desired_field in desired.fields.iter().filter(|f| f.name != "id") {
+            match live.fields.iter().find(|f| f.name == desired_field.name) {
+                None => diff.added.push(desired_field.clone()),
+                Some(live_field) => {
+                    let changes = field_changes(live_field, desired_field);
+                    if changes.is_empty() {
+                        continue;
+                    }
+                    if changes.iter().any(|c| c.attribute == FieldAttribute::Type) {
+                        diff.reindex_reasons
+                            .push(ReindexReason::TypeChanged(desired_field.name.clone()));
+                    }
+                    if changes.iter().any(|c| {
+                        matches!(c.attribute, FieldAttribute::NumDim | FieldAttribute::Embed)
+                    }) {
+                        diff.reindex_reasons
+                            .push(ReindexReason::VectorChanged(desired_field.name.clone()));
+                    }
+                    if live_field.store == Some(false)
+                        || changes.iter().any(|c| c.attribute == FieldAttribute::Store)
+                    {
+                        diff.reindex_reasons
+                            .push(ReindexReason::UnstoredField(desired_field.name.clone()));
+                    }
+                    diff.changed.push(FieldChange {
+                        live: live_field.clone(),
+                        desired: desired_field.clone(),
+                        changes,
+                    });
+                }
+            }
+        }
+
+        let is_nested_in = |name: &str, fields: &[Field]| {
+            fields.iter().any(|f| {
+                f.r#type.starts_with("object")
+                    && name
+                        .strip_prefix(f.name.as_str())
+                        .is_some_and(|rest| rest.starts_with('.'))
+            })
+        };
+        diff.removed = live
+            .fields
+            .iter()
+            .filter(|f| f.name != "id" && !desired.fields.iter().any(|d| d.name == f.name))
+            .filter(|f| !is_nested_in(&f.name, &live.fields))
+            .cloned()
+            .collect();
+
+        let live_sorting_field = live.default_sorting_field.as_deref().unwrap_or_default();
+        let desired_sorting_field = desired.default_sorting_field.as_deref().unwrap_or_default();
+        if live_sorting_field != desired_sorting_field {
+            diff.reindex_reasons
+                .push(ReindexReason::CollectionSetting("default_sorting_field"));
+        }
+        if live.enable_nested_fields.unwrap_or(false)
+            != desired.enable_nested_fields.unwrap_or(false)
+        {
+            diff.reindex_reasons
+                .push(ReindexReason::CollectionSetting("enable_nested_fields"));
+        }
+        if live.token_separators.as_deref().unwrap_or_default()
+            != desired.token_separators.as_deref().unwrap_or_default()
+        {
+            diff.reindex_reasons
+                .push(ReindexReason::CollectionSetting("token_separators"));
+        }
+        if live.symbols_to_index.as_deref().unwrap_or_default()
+            != desired.symbols_to_index.as_deref().unwrap_or_default()
+        {
+            diff.reindex_reasons
+                .push(ReindexReason::CollectionSetting("symbols_to_index"));
+        }
+
+        if let Some(synonym_sets) = &desired.synonym_sets
+            && live.synonym_sets.as_ref() != Some(synonym_sets)
+        {
+            diff.synonym_sets = Some(synonym_sets.clone());
+        }
+
+        diff
+    }
+
+    /// Whether the live collection already matches the desired schema.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.removed.is_empty()
+            && self.changed.is_empty()
+            && self.synonym_sets.is_none()
+            && self.reindex_reasons.is_empty()
+    }
+
+    /// Whether some differences cannot be applied in place and require a reindex.
+    #[inline]
+    pub fn requires_reindex(&self) -> bool {
+        !self.reindex_reasons.is_empty()
+    }
+
+    /// The `CollectionUpdateSchema` that migrates the live collection in place, or `None` if
+    /// there is nothing to update.
+    ///
+    /// Removed fields are dropped, changed fields are dropped and re-added with their new
+    /// definition, and added fields are added. Differences listed in `reindex_reasons` are
+    /// not covered.
+    pub fn update_schema(&self) -> Option<CollectionUpdateSchema> {
+        let drop = |name: &str| Field {
+            name: name.to_owned(),
+            drop: Some(true),
+            ..Default::default()
+        };
+
+        let fields = self
+            .removed
+            .iter()
+            .map(|f| drop(&f.name))
+            .chain(
+                self.changed
+                    .iter()
+                    .flat_map(|c| [drop(&c.desired.name), c.desired.clone()]),
+            )
+            .chain(self.added.iter().cloned())
+            .collect::<Vec<_>>();
+
+        if fields.is_empty() && self.synonym_sets.is_none() {
+            return None;
+        }
+        Some(CollectionUpdateSchema {
+            fields: (!fields.is_empty()).then_some(fields),
+            synonym_sets: self.synonym_sets.clone(),
+            ..Default::default()
+        })
+    }
+}
+
+/// Compares the attributes of two fields with the same name, filling in server-side defaults.
+fn field_changes(live: &Field, desired: &Field) -> Vec<FieldAttributeChange> {
+    let default_sort = |field: &Field| is_sortable_by_default(&field.r#type);
+    // Model credentials are left out, since Typesense does not return them in full.
+    let embed = |field: &Field| {
+        field.embed.as_ref().map_or_else(String::new, |embed| {
+            format!(
+                "{}({})",
+                embed.model_config.model_name,
+                embed.from.join(",")
+            )
+        })
+    };
+
+    let attributes = [
+        (
+            FieldAttribute::Type,
+            live.r#type.clone(),
+            desired.r#type.clone(),
+        ),
+        (
+            FieldAttribute::Facet,
+            live.facet.unwrap_or(false).to_string(),
+            desired.facet.unwrap_or(false).to_string(),
+        ),
+        (
+            FieldAttribute::Sort,
+            live.sort.unwrap_or_else(|| default_sort(live)).to_string(),
+            desired
+                .sort
+                .unwrap_or_else(|| default_sort(desired))
+                .to_string(),
+        ),
+        (
+            FieldAttribute::Index,
+            live.index.unwrap_or(true).to_string(),
+            desired.index.unwrap_or(true).to_string(),
+        ),
+        (
+            FieldAttribute::Optional,
+            live.optional.unwrap_or(false).to_string(),
+            desired.optional.unwrap_or(false).to_string(),
+        ),
+        (
+            FieldAttribute::Infix,
+            live.infix.unwrap_or(false).to_string(),
+            desired.infix.unwrap_or(false).to_string(),
+        ),
+        (
+            FieldAttribute::Locale,
+            live.locale.clone().unwrap_or_default(),
+            desired.locale.clone().unwrap_or_default(),
+        ),
+        (
+            FieldAttribute::Stem,
+            live.stem.unwrap_or(false).to_string(),
+            desired.stem.unwrap_or(false).to_string(),
+        ),
+        (
+            FieldAttribute::Store,
+            live.store.unwrap_or(true).to_string(),
+            desired.store.unwrap_or(true).to_string(),
+        ),
+        (
+            FieldAttribute::Reference,
+            live.reference.clone().unwrap_or_default(),
+            desired.reference.clone().unwrap_or_default(),
+        ),
+        (
+            FieldAttribute::NumDim,
+            live.num_dim.map(|n| n.to_string()).unwrap_or_default(),
+            desired.num_dim.map(|n| n.to_string()).unwrap_or_default(),
+        ),
+        (FieldAttribute::Embed, embed(live), embed(desired)),
+    ];
+
+    attributes
+        .into_iter()
+        .filter(|(_, live, desired)| live != desired)
+        .map(|(attribute, live, desired)| FieldAttributeChange {
+            attribute,
+            live,
+            desired,
+        })
+        .collect()
+}
@@ -0,0 +1,73 @@
+use serde::{Deserialize, Deserializer, de::DeserializeOwned};
+use typesense_codegen::models::SearchResultHit;
+
+/// Options for `Documents::scroll`.
+#[derive(Clone, Debug, bon::Builder)]
+#[builder(on(String, into))]
+pub struct ScrollOptions {
+    /// The numeric field that the hits are ordered by, e.g. `created_at`.
+    ///
+    /// Every document matching the search must have a value for this field. Hits with equal
+    /// values are ordered by their `id`.
+    pub sort_field: String,
+    /// Walk the hits from the largest to the smallest value of `sort_field`.
+    #[builder(default)]
+    pub descending: bool,
+    /// The number of hits fetched per request, up to Typesense's maximum of 250.
+    #[builder(default = 250)]
+    pub per_page: i32,
+}
+
+/// A document of a `scroll` hit, along with the JSON it was read from, which holds the values
+/// the next page starts after.
+pub(crate) struct KeysetDocument<D> {
+    document: D,
+    json: serde_json::Value,
+}
+
+impl<'de, D: DeserializeOwned> Deserialize<'de> for KeysetDocument<D> {
+    fn deserialize<De: Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error> {
+        let json = serde_json::Value::deserialize(deserializer)?;
+        let document = D::deserialize(&json).map_err(serde::de::Error::custom)?;
+        Ok(Self { document, json })
+    }
+}
+
+impl<D> KeysetDocument<D> {
+    /// The value of `sort_field` (a dotted path for nested fields) and the `id` of the document.
+    pub(crate) fn keyset(
+        &self,
+        sort_field: &str,
+    ) -> Result<(serde_json::Number, String), serde_json::Error> {
+        let value = sort_field
+            .split('.')
+            .try_fold(&self.json, |value, key| value.get(key))
+            .and_then(serde_json::Value::as_number)
+            .ok_or_else(|| {
+                <serde_json::Error as serde::de::Error>::custom(format!(
+                    "hit has no numeric value for the sort field `{sort_field}`"
+                ))
+            })?;
+        let id = self
+            .json
+            .get("id")
+            .and_then(serde_json::Value::as_str)
+            .ok_or_else(|| <serde_json::Error as serde::de::Error>::custom("hit has no `id`"))?;
+        Ok((value.clone(), id.to_owned()))
+    }
+
+    /// Replaces the document of `hit` with the `D` it was deserialized into.
+    pub(crate) fn into_hit(hit: SearchResultHit<Self>) -> SearchResultHit<D> {
+        SearchResultHit {
+            highlights: hit.highlights,
+            highlight: hit.highlight,
+            document: hit.document.map(|document| document.document),
+            text_match: hit.text_match,
+            text_match_info: hit.text_match_info,
+            geo_distance_meters: hit.geo_distance_meters,
+            vector_distance: hit.vector_distance,
+            hybrid_search_info: hit.hybrid_search_info,
+            search_index: hit.search_index,
+        }
+    }
+}
@@ -0,0 +1,12 @@
+/// Options for `Documents::search_pages`.
+#[derive(Clone, Debug, Default, bon::Builder)]
+pub struct SearchPagesOptions {
+    /// Request the next page while the current one is fetched.
+    ///
+    /// The next page's request is sent before the current page is yielded, so it is usually
+    /// answered by the time it is needed. This keeps at most one extra request in flight.
+    /// Prefetching starts with the second page, once the `found` count of the first response
+    /// shows that there are more pages, so no request is sent past the last page.
+    #[builder(default)]
+    pub prefetch: bool,
+}
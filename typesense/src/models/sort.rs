@@ -0,0 +1,301 @@
+use super::{Filter, GeoDistance, GeoPoint, IndexedField, SortableField};
+use std::{borrow::Cow, fmt};
+
+/// The direction of a sort.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SortOrder {
+    /// Smallest values first.
+    Asc,
+    /// Largest values first.
+    #[default]
+    Desc,
+}
+
+impl fmt::Display for SortOrder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            SortOrder::Asc => "asc",
+            SortOrder::Desc => "desc",
+        })
+    }
+}
+
+/// A single expression of a `SortBy`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SortField {
+    expr: String,
+    order: SortOrder,
+}
+
+impl SortField {
+    /// Sorts by a field, smallest values first.
+    pub fn asc(field: impl SortableField) -> Self {
+        Self {
+            expr: field.into_field_name(),
+            order: SortOrder::Asc,
+        }
+    }
+
+    /// Sorts by a field, largest values first.
+    pub fn desc(field: impl SortableField) -> Self {
+        Self {
+            expr: field.into_field_name(),
+            order: SortOrder::Desc,
+        }
+    }
+
+    /// Sorts by the text match score (`_text_match`).
+    pub fn text_match(order: SortOrder) -> TextMatchSort {
+        TextMatchSort {
+            order,
+            buckets: None,
+            bucket_size: None,
+        }
+    }
+
+    /// Sorts documents that match `filter` before or after the rest (`_eval(filter)`).
+    pub fn eval(filter: Filter, order: SortOrder) -> Self {
+        Self {
+            expr: format!("_eval({filter})"),
+            order,
+        }
+    }
+
+    /// Scores every document by the sum of the weights of the filters it matches, and sorts by
+    /// that score (`_eval([(filter):weight, ...])`).
+    pub fn eval_weighted(
+        filters: impl IntoIterator<Item = (Filter, i64)>,
+        order: SortOrder,
+    ) -> Self {
+        let filters = filters
+            .into_iter()
+            .map(|(filter, weight)| format!("({filter}):{weight}"))
+            .collect::<Vec<_>>();
+        Self {
+            expr: format!("_eval([{}])", filters.join(", ")),
+            order,
+        }
+    }
+
+    /// Sorts by the distance of a geopoint field from `point`.
+    pub fn geo_distance(
+        field: impl IndexedField,
+        point: impl Into<GeoPoint>,
+        order: SortOrder,
+    ) -> GeoSort {
+        GeoSort {
+            field: field.into_field_name(),
+            point: point.into(),
+            order,
+            exclude_radius: None,
+            precision: None,
+        }
+    }
+
+    /// Sorts by the distance to the query vector of a vector search (`_vector_distance`).
+    pub fn vector_distance(order: SortOrder) -> Self {
+        Self {
+            expr: "_vector_distance".to_owned(),
+            order,
+        }
+    }
+
+    /// Sorts the groups of a `group_by` search by their number of hits (`_group_found`).
+    pub fn group_found(order: SortOrder) -> Self {
+        Self {
+            expr: "_group_found".to_owned(),
+            order,
+        }
+    }
+}
+
+impl fmt::Display for SortField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.expr, self.order)
+    }
+}
+
+/// A sort by the text match score. Created with `SortField::text_match`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TextMatchSort {
+    order: SortOrder,
+    buckets: Option<u32>,
+    bucket_size: Option<u32>,
+}
+
+impl TextMatchSort {
+    /// Divides the hits into `buckets` groups of equal size, which are treated as equally
+    /// relevant so that the next sort field decides the order within them.
+    pub fn buckets(mut self, buckets: u32) -> Self {
+        self.buckets = Some(buckets);
+        self
+    }
+
+    /// Divides the hits into groups of `bucket_size` hits, which are treated as equally relevant.
+    pub fn bucket_size(mut self, bucket_size: u32) -> Self {
+        self.bucket_size = Some(bucket_size);
+        self
+    }
+}
+
+impl From<TextMatchSort> for SortField {
+    fn from(sort: TextMatchSort) -> Self {
+        let arguments = [
+            sort.buckets.map(|n| format!("buckets: {n}")),
+            sort.bucket_size.map(|n| format!("bucket_size: {n}")),
+        ];
+        SortField {
+            expr: call("_text_match", arguments.into_iter().flatten()),
+            order: sort.order,
+        }
+    }
+}
+
+/// A sort by the distance from a point. Created with `SortField::geo_distance`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GeoSort {
+    field: String,
+    point: GeoPoint,
+    order: SortOrder,
+    exclude_radius: Option<GeoDistance>,
+    precision: Option<GeoDistance>,
+}
+
+impl GeoSort {
+    /// Treats all documents within `radius` of the point as equally distant, so that the next
+    /// sort field decides their order.
+    pub fn exclude_radius(mut self, radius: GeoDistance) -> Self {
+        self.exclude_radius = Some(radius);
+        self
+    }
+
+    /// Rounds the distances to multiples of `precision`, so that documents in the same band
+    /// are ordered by the next sort field.
+    pub fn precision(mut self, precision: GeoDistance) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+}
+
+impl From<GeoSort> for SortField {
+    fn from(sort: GeoSort) -> Self {
+        // Unlike in filters, sort distances are written without a space: `2mi`.
+        let distance = |distance: GeoDistance| match distance {
+            GeoDistance::Km(km) => format!("{km}km"),
+            GeoDistance::Miles(miles) => format!("{miles}mi"),
+        };
+        let arguments = [
+            Some(format!("{}, {}", sort.point.lat, sort.point.lng)),
+            sort.exclude_radius
+                .map(|r| format!("exclude_radius: {}", distance(r))),
+            sort.precision
+                .map(|p| format!("precision: {}", distance(p))),
+        ];
+        SortField {
+            expr: call(&sort.field, arguments.into_iter().flatten()),
+            order: sort.order,
+        }
+    }
+}
+
+/// Renders `name(arg1, arg2)`, or just `name` without arguments.
+fn call(name: &str, arguments: impl Iterator<Item = String>) -> String {
+    let arguments = arguments.collect::<Vec<_>>();
+    if arguments.is_empty() {
+        name.to_owned()
+    } else {
+        format!("{name}({})", arguments.join(", "))
+    }
+}
+
+/// A `sort_by` expression of up to three sort fields, the most Typesense supports.
+///
+/// The number of fields `N` is part of the type: `then` is only available while there are
+/// fewer than three, so a fourth sort field is rejected at compile time. A `SortBy` converts
+/// into the `Cow<str>` that the `sort_by` parameters take.
+///
+/// # Example
+/// ```
+/// use typesense::models::{Filter, GeoDistance, SearchParameters, SortBy, SortField, SortOrder};
+///
+/// let sort_by = SortBy::by(SortField::eval(Filter::field("brand").eq("Acme"), SortOrder::Desc))
+///     .then(SortField::text_match(SortOrder::Desc).buckets(10))
+///     .then(
+///         SortField::geo_distance("location", (48.85, 2.29), SortOrder::Asc)
+///             .exclude_radius(GeoDistance::Miles(2.0)),
+///     );
+/// assert_eq!(
+///     sort_by.to_string(),
+///     "_eval(brand:=`Acme`):desc,_text_match(buckets: 10):desc,\
+///      location(48.85, 2.29, exclude_radius: 2mi):asc"
+/// );
+///
+/// let params = SearchParameters::builder().q("shoe").sort_by(sort_by).build();
+/// ```
+///
+/// ```compile_fail
+/// use typesense::models::{SortBy, SortField};
+///
+/// // Typesense sorts by at most three fields.
+/// SortBy::by(SortField::asc("a"))
+///     .then(SortField::asc("b"))
+///     .then(SortField::asc("c"))
+///     .then(SortField::asc("d"));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct SortBy<const N: usize> {
+    fields: [SortField; N],
+}
+
+impl SortBy<1> {
+    /// Sorts by a single field.
+    pub fn by(field: impl Into<SortField>) -> Self {
+        Self {
+            fields: [field.into()],
+        }
+    }
+
+    /// Adds a second sort field, which orders hits that are equal by the first.
+    pub fn then(self, field: impl Into<SortField>) -> SortBy<2> {
+        let [first] = self.fields;
+        SortBy {
+            fields: [first, field.into()],
+        }
+    }
+}
+
+impl SortBy<2> {
+    /// Adds the third and last sort field, which orders hits that are equal by the first two.
+    pub fn then(self, field: impl Into<SortField>) -> SortBy<3> {
+        let [first, second] = self.fields;
+        SortBy {
+            fields: [first, second, field.into()],
+        }
+    }
+}
+
+impl<const N: usize> fmt::Display for SortBy<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, field) in self.fields.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            write!(f, "{field}")?;
+        }
+        Ok(())
+    }
+}
+
+impl<const N: usize> From<SortBy<N>> for String {
+    #[inline]
+    fn from(sort_by: SortBy<N>) -> Self {
+        sort_by.to_string()
+    }
+}
+
+impl<'a, const N: usize> From<SortBy<N>> for Cow<'a, str> {
+    #[inline]
+    fn from(sort_by: SortBy<N>) -> Self {
+        Cow::Owned(sort_by.to_string())
+    }
+}
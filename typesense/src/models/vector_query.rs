@@ -0,0 +1,152 @@
+use super::IndexedField;
+use crate::VectorQueryError;
+use std::{borrow::Cow, fmt};
+
+/// A `vector_query` expression for nearest-neighbor and hybrid searches.
+///
+/// The query vector is given directly with `VectorQuery::vector`, taken from a stored document
+/// with `VectorQuery::similar_to`, or computed from `q` by the field's embedding model with
+/// `VectorQuery::embed_query`. A `VectorQuery` converts into the `Cow<str>` that the
+/// `vector_query` parameter takes; searches whose vector makes the URL too long are sent as a
+/// POST request.
+///
+/// # Example
+/// ```
+/// use typesense::models::{SearchParameters, VectorQuery};
+///
+/// let vector_query = VectorQuery::vector("embedding", &[0.1, 0.25, -0.5])
+///     .k(100)
+///     .distance_threshold(0.3);
+/// assert_eq!(
+///     vector_query.to_string(),
+///     "embedding:([0.1,0.25,-0.5], k:100, distance_threshold:0.3)"
+/// );
+///
+/// // Hybrid search: `q` is matched by keywords and embedded into a query vector.
+/// let params = SearchParameters::builder()
+///     .q("running shoes")
+///     .query_by("title,embedding")
+///     .vector_query(VectorQuery::embed_query("embedding").alpha(0.8))
+///     .build();
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct VectorQuery {
+    field: String,
+    vector: Vec<f32>,
+    id: Option<String>,
+    k: Option<u32>,
+    alpha: Option<f32>,
+    distance_threshold: Option<f32>,
+    ef: Option<u32>,
+    flat_search_cutoff: Option<u32>,
+}
+
+impl VectorQuery {
+    fn new(field: impl IndexedField, vector: Vec<f32>, id: Option<String>) -> Self {
+        Self {
+            field: field.into_field_name(),
+            vector,
+            id,
+            k: None,
+            alpha: None,
+            distance_threshold: None,
+            ef: None,
+            flat_search_cutoff: None,
+        }
+    }
+
+    /// Finds the documents whose `field` is nearest to `vector`.
+    pub fn vector(field: impl IndexedField, vector: &[f32]) -> Self {
+        Self::new(field, vector.to_vec(), None)
+    }
+
+    /// Finds the documents whose `field` is nearest to that of the document with the id `id`.
+    ///
+    /// Returns an error if `id` contains a comma, a parenthesis or a backtick, which
+    /// `vector_query` cannot escape.
+    pub fn similar_to(
+        field: impl IndexedField,
+        id: impl Into<String>,
+    ) -> Result<Self, VectorQueryError> {
+        let id = id.into();
+        if id.contains([',', '(', ')', '`']) {
+            return Err(VectorQueryError::InvalidId(id));
+        }
+        Ok(Self::new(field, Vec::new(), Some(id)))
+    }
+
+    /// Embeds `q` with the embedding model of the auto-embedding `field`, which must be listed
+    /// in `query_by` as well. Used to set the options of a hybrid search.
+    pub fn embed_query(field: impl IndexedField) -> Self {
+        Self::new(field, Vec::new(), None)
+    }
+
+    /// The number of nearest neighbors to return.
+    pub fn k(mut self, k: u32) -> Self {
+        self.k = Some(k);
+        self
+    }
+
+    /// The weight of the vector search in the rank fusion of a hybrid search, between `0.0` and
+    /// `1.0`. The keyword search is weighted `1 - alpha`.
+    pub fn alpha(mut self, alpha: f32) -> Self {
+        self.alpha = Some(alpha);
+        self
+    }
+
+    /// Leaves out documents whose vector distance is larger than `distance_threshold`.
+    pub fn distance_threshold(mut self, distance_threshold: f32) -> Self {
+        self.distance_threshold = Some(distance_threshold);
+        self
+    }
+
+    /// The size of the candidate list of the HNSW search. Larger values are more accurate, but
+    /// slower.
+    pub fn ef(mut self, ef: u32) -> Self {
+        self.ef = Some(ef);
+        self
+    }
+
+    /// Searches by brute force instead of through the HNSW index when the filter leaves at most
+    /// `flat_search_cutoff` documents.
+    pub fn flat_search_cutoff(mut self, flat_search_cutoff: u32) -> Self {
+        self.flat_search_cutoff = Some(flat_search_cutoff);
+        self
+    }
+}
+
+impl fmt::Display for VectorQuery {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let vector = self.vector.iter().map(f32::to_string).collect::<Vec<_>>();
+        write!(f, "{}:([{}]", self.field, vector.join(","))?;
+
+        let options = [
+            self.id.as_ref().map(|id| format!("id: {id}")),
+            self.k.map(|k| format!("k:{k}")),
+            self.alpha.map(|alpha| format!("alpha:{alpha}")),
+            self.distance_threshold
+                .map(|threshold| format!("distance_threshold:{threshold}")),
+            self.ef.map(|ef| format!("ef:{ef}")),
+            self.flat_search_cutoff
+                .map(|cutoff| format!("flat_search_cutoff:{cutoff}")),
+        ];
+        for option in options.into_iter().flatten() {
+            write!(f, ", {option}")?;
+        }
+        f.write_str(")")
+    }
+}
+
+impl From<VectorQuery> for String {
+    #[inline]
+    fn from(vector_query: VectorQuery) -> Self {
+        vector_query.to_string()
+    }
+}
+
+impl<'a> From<VectorQuery> for Cow<'a, str> {
+    #[inline]
+    fn from(vector_query: VectorQuery) -> Self {
+        Cow::Owned(vector_query.to_string())
+    }
+}
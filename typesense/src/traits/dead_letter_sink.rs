@@ -0,0 +1,47 @@
+//! # Dead-letter sinks
+//!
+//! Destinations for import lines that could not be imported, used by
+//! `Documents::import_jsonl_with_retry`.
+use crate::models::FailedImportLine;
+use std::{io, sync::mpsc};
+
+/// A destination for import lines that failed permanently or exhausted their retries.
+pub trait DeadLetterSink {
+    /// Receives a single failed line together with its error.
+    fn send(&mut self, line: FailedImportLine) -> io::Result<()>;
+}
+
+/// Collects the failed lines in memory.
+impl DeadLetterSink for Vec<FailedImportLine> {
+    #[inline]
+    fn send(&mut self, line: FailedImportLine) -> io::Result<()> {
+        self.push(line);
+        Ok(())
+    }
+}
+
+/// Forwards the failed lines to a channel. Fails once the receiver has been dropped.
+impl DeadLetterSink for mpsc::Sender<FailedImportLine> {
+    #[inline]
+    fn send(&mut self, line: FailedImportLine) -> io::Result<()> {
+        mpsc::Sender::send(self, line).map_err(|e| io::Error::new(io::ErrorKind::BrokenPipe, e))
+    }
+}
+
+/// Writes every failed line as a JSON object on its own line, e.g. to a file.
+///
+/// ```no_run
+/// use std::{fs::File, io::BufWriter};
+/// use typesense::prelude::JsonlDeadLetters;
+///
+/// let sink = JsonlDeadLetters(BufWriter::new(File::create("failed.jsonl").unwrap()));
+/// ```
+#[derive(Debug)]
+pub struct JsonlDeadLetters<W>(pub W);
+
+impl<W: io::Write> DeadLetterSink for JsonlDeadLetters<W> {
+    fn send(&mut self, line: FailedImportLine) -> io::Result<()> {
+        serde_json::to_writer(&mut self.0, &line)?;
+        self.0.write_all(b"\n")
+    }
+}
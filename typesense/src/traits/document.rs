@@ -15,9 +15,75 @@ pub trait Document: DeserializeOwned + Serialize {
     /// Collection name
     const COLLECTION_NAME: &'static str;
 
+    /// The collections that the document's `reference` fields point to, e.g. `customers` for a
+    /// field with `#[typesense(reference = "customers.id")]`.
+    const REFERENCED_COLLECTIONS: &'static [&'static str] = &[];
+
     /// A struct for partial updates
     type Partial: DocumentPartial;
 
     /// Collection schema associated with the document.
     fn collection_schema() -> CollectionSchema<'static>;
 }
+
+/// What a `$collection(...)` include embeds in the documents of a search: a document of the
+/// joined collection, a list of them, or an optional one.
+pub trait JoinedDocument: DeserializeOwned + Serialize {
+    /// The document type of the joined collection.
+    type Document: Document;
+}
+
+impl<T: Document> JoinedDocument for T {
+    type Document = T;
+}
+
+impl<T: Document> JoinedDocument for Vec<T> {
+    type Document = T;
+}
+
+impl<T: Document> JoinedDocument for Option<T> {
+    type Document = T;
+}
+
+/// Typed references to the fields of a document, generated by `#[derive(Typesense)]`.
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use typesense::{Typesense, models::{FacetBy, Filter}, prelude::*};
+///
+/// #[derive(Typesense, Serialize, Deserialize)]
+/// struct Company {
+///     company_name: String,
+///     #[typesense(facet)]
+///     country: String,
+/// }
+///
+/// let facet_by = FacetBy::new().field(Company::fields().country);
+/// let filter = Filter::field(Company::fields().company_name).eq("Acme");
+/// assert_eq!(facet_by.to_string(), "country");
+/// ```
+///
+/// `company_name` has no `#[typesense(facet)]`, so it cannot be faceted on:
+/// ```compile_fail
+/// # use serde::{Deserialize, Serialize};
+/// # use typesense::{Typesense, models::FacetBy, prelude::*};
+/// # #[derive(Typesense, Serialize, Deserialize)]
+/// # struct Company {
+/// #     company_name: String,
+/// # }
+/// let facet_by = FacetBy::new().field(Company::fields().company_name);
+/// ```
+pub trait DocumentFields {
+    /// A struct with a `FieldRef` for every field in the collection schema, and the nested
+    /// field references of every `#[typesense(flatten)]` field.
+    type Fields;
+
+    /// The field references, with every field name prefixed by `prefix`, e.g. `address.`.
+    fn prefixed_fields(prefix: &str) -> Self::Fields;
+
+    /// The field references.
+    #[inline]
+    fn fields() -> Self::Fields {
+        Self::prefixed_fields("")
+    }
+}
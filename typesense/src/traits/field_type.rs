@@ -1,4 +1,7 @@
-use crate::traits::Document;
+use crate::{
+    models::{GeoPoint, GeoPolygon},
+    traits::Document,
+};
 use std::collections::{BTreeMap, HashMap};
 /// Type for a field. Currently it is a wrapping to a `String` but it could be extended to a enum
 pub type FieldType = String;
@@ -53,6 +56,8 @@ impl_to_typesense_field!(usize, "int64");
 impl_to_typesense_field!(f32, "float");
 impl_to_typesense_field!(f64, "float");
 impl_to_typesense_field!(bool, "bool");
+impl_to_typesense_field!(GeoPoint, "geopoint");
+impl_to_typesense_field!(GeoPolygon, "geopolygon");
 impl_to_typesense_field!(HashMap<String, T>, "object", T);
 impl_to_typesense_field!(BTreeMap<String, T>, "object", T);
 
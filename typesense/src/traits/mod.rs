@@ -1,9 +1,17 @@
 //! Contains the core traits and extensions for Typesense client operations
 
+mod dead_letter_sink;
 mod document;
 mod field_type;
+mod multi_search_documents;
 mod multi_search_ext;
+mod search_result_ext;
+mod search_result_hit_ext;
 
+pub use dead_letter_sink::*;
 pub use document::*;
 pub use field_type::*;
+pub use multi_search_documents::*;
 pub use multi_search_ext::MultiSearchResultExt;
+pub use search_result_ext::*;
+pub use search_result_hit_ext::*;
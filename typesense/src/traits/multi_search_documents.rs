@@ -0,0 +1,65 @@
+use crate::{
+    MultiSearchParseError,
+    models::{MultiSearchResult, SearchResult},
+    traits::MultiSearchResultExt,
+};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+/// The document types of the searches of a typed multi-search, as a tuple like
+/// `(Product, Brand)`.
+pub trait MultiSearchDocuments {
+    /// The results of the searches, like
+    /// `(Result<SearchResult<Product>, _>, Result<SearchResult<Brand>, _>)`.
+    type Results;
+
+    /// Parses every result of a multi-search response into the document type of its search.
+    fn parse_results(response: &MultiSearchResult<Value>) -> Self::Results;
+}
+
+/// A tuple of document types that one more search can be added to.
+#[diagnostic::on_unimplemented(
+    message = "a typed multi-search takes at most 8 searches",
+    note = "use `MultiSearch::perform` with `MultiSearchResultExt::parse_at` for more searches"
+)]
+pub trait PushSearch<D> {
+    /// The tuple with `D` appended.
+    type Output;
+}
+
+macro_rules! impl_multi_search_documents {
+    ($($d:ident => $i:tt),*) => {
+        impl<$($d: DeserializeOwned),*> MultiSearchDocuments for ($($d,)*) {
+            type Results = ($(Result<SearchResult<$d>, MultiSearchParseError>,)*);
+
+            fn parse_results(response: &MultiSearchResult<Value>) -> Self::Results {
+                ($(response.parse_at::<$d>($i),)*)
+            }
+        }
+    };
+}
+
+impl_multi_search_documents!(A => 0);
+impl_multi_search_documents!(A => 0, B => 1);
+impl_multi_search_documents!(A => 0, B => 1, C => 2);
+impl_multi_search_documents!(A => 0, B => 1, C => 2, E => 3);
+impl_multi_search_documents!(A => 0, B => 1, C => 2, E => 3, F => 4);
+impl_multi_search_documents!(A => 0, B => 1, C => 2, E => 3, F => 4, G => 5);
+impl_multi_search_documents!(A => 0, B => 1, C => 2, E => 3, F => 4, G => 5, H => 6);
+impl_multi_search_documents!(A => 0, B => 1, C => 2, E => 3, F => 4, G => 5, H => 6, I => 7);
+
+macro_rules! impl_push_search {
+    ($($d:ident),*) => {
+        impl<$($d,)* D> PushSearch<D> for ($($d,)*) {
+            type Output = ($($d,)* D,);
+        }
+    };
+}
+
+impl_push_search!(A);
+impl_push_search!(A, B);
+impl_push_search!(A, B, C);
+impl_push_search!(A, B, C, E);
+impl_push_search!(A, B, C, E, F);
+impl_push_search!(A, B, C, E, F, G);
+impl_push_search!(A, B, C, E, F, G, H);
@@ -0,0 +1,29 @@
+use crate::models::{FacetResult, FacetableField, SearchResult};
+
+/// An extension trait for `SearchResult` to provide typed access to its facet counts.
+pub trait SearchResultExt {
+    /// The facet counts of `field`, if it was listed in `facet_by`.
+    fn facet(&self, field: impl FacetableField) -> Option<FacetResult>;
+
+    /// The facet counts of every field listed in `facet_by`.
+    fn facets(&self) -> Vec<FacetResult>;
+}
+
+impl<D> SearchResultExt for SearchResult<D> {
+    fn facet(&self, field: impl FacetableField) -> Option<FacetResult> {
+        let field = field.into_field_name();
+        self.facet_counts
+            .iter()
+            .flatten()
+            .find(|facet_counts| facet_counts.field_name.as_deref() == Some(field.as_str()))
+            .map(FacetResult::from)
+    }
+
+    fn facets(&self) -> Vec<FacetResult> {
+        self.facet_counts
+            .iter()
+            .flatten()
+            .map(FacetResult::from)
+            .collect()
+    }
+}
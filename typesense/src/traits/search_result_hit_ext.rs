@@ -0,0 +1,79 @@
+use crate::models::{FieldHighlight, FieldName, IndexedField, SearchResultHit};
+
+/// The scores of a hit of a hybrid search, which combines a keyword and a vector search.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HybridSearchScores {
+    /// The score from the rank fusion of the keyword and the vector search, which orders the hits.
+    pub rank_fusion_score: f32,
+    /// The score of the keyword match, if the document matched the keywords.
+    pub text_match: Option<i64>,
+    /// The distance between the query vector and the document's vector, if the document was
+    /// among the nearest neighbors.
+    pub vector_distance: Option<f32>,
+}
+
+/// An extension trait for `SearchResultHit` to provide typed access to the scores, geo
+/// distances and highlights of a hit.
+pub trait SearchResultHitExt {
+    /// The distance between the query vector and the document's vector, for vector and hybrid
+    /// searches. Smaller is more similar.
+    fn vector_distance(&self) -> Option<f32>;
+
+    /// The cosine similarity between the query vector and the document's vector, between `-1.0`
+    /// and `1.0`, for fields with the default `cosine` distance. Larger is more similar.
+    fn vector_similarity(&self) -> Option<f32> {
+        self.vector_distance().map(|distance| 1.0 - distance)
+    }
+
+    /// The scores of a hit of a hybrid search, or `None` for other searches.
+    fn hybrid_scores(&self) -> Option<HybridSearchScores>;
+
+    /// The distance in meters between the point of a geo sort or filter on `field` and the
+    /// document's geopoint.
+    fn geo_distance_meters(&self, field: impl IndexedField) -> Option<i32>;
+
+    /// The highlights of every matched string, including those in nested objects and arrays.
+    ///
+    /// They are taken from the `highlight` object, or from the deprecated `highlights` list
+    /// when the hit has no `highlight` object.
+    fn highlights(&self) -> Vec<FieldHighlight>;
+
+    /// The highlights of the field at the dotted path `field`, e.g. `author.name`. Array fields
+    /// have a highlight per matched element.
+    fn highlights_of(&self, field: impl FieldName) -> Vec<FieldHighlight> {
+        let field = field.into_field_name();
+        self.highlights()
+            .into_iter()
+            .filter(|highlight| highlight.path == field)
+            .collect()
+    }
+}
+
+impl<D> SearchResultHitExt for SearchResultHit<D> {
+    #[inline]
+    fn vector_distance(&self) -> Option<f32> {
+        self.vector_distance
+    }
+
+    fn hybrid_scores(&self) -> Option<HybridSearchScores> {
+        let rank_fusion_score = self.hybrid_search_info.as_ref()?.rank_fusion_score?;
+        Some(HybridSearchScores {
+            rank_fusion_score,
+            text_match: self.text_match,
+            vector_distance: self.vector_distance,
+        })
+    }
+
+    fn geo_distance_meters(&self, field: impl IndexedField) -> Option<i32> {
+        let field = field.into_field_name();
+        self.geo_distance_meters.as_ref()?.get(&field).copied()
+    }
+
+    fn highlights(&self) -> Vec<FieldHighlight> {
+        match (&self.highlight, &self.highlights) {
+            (Some(highlight), _) => FieldHighlight::from_highlight_map(highlight),
+            (None, Some(highlights)) => FieldHighlight::from_search_highlights(highlights),
+            (None, None) => Vec::new(),
+        }
+    }
+}
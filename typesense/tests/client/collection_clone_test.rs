@@ -0,0 +1,274 @@
+#![cfg(not(target_family = "wasm"))]
+
+use super::get_test_client;
+use typesense::{
+    CloneError,
+    models::{CollectionCloneOptions, CollectionResponse, Field},
+};
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{body_json, body_partial_json, method, path, query_param},
+};
+
+fn field(name: &str, r#type: &str) -> Field {
+    Field {
+        name: name.to_owned(),
+        r#type: r#type.to_owned(),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_clone_to_copies_schema_synonyms_and_documents() {
+    let mock_server = MockServer::start().await;
+    let client = get_test_client(&mock_server.uri());
+
+    Mock::given(method("GET"))
+        .and(path("/collections/products"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(CollectionResponse {
+                name: "products".to_owned(),
+                fields: vec![
+                    field("title", "string"),
+                    field("country", "string"),
+                    field("embedding", "float[]"),
+                ],
+                synonym_sets: Some(vec!["brands".to_owned()]),
+                num_documents: 3,
+                ..Default::default()
+            }),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let synonym_items = serde_json::json!([{"id": "tv", "synonyms": ["tv", "television"]}]);
+    Mock::given(method("GET"))
+        .and(path("/synonym_sets/brands"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "name": "brands",
+            "items": synonym_items,
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path("/synonym_sets/brands_products_staging"))
+        .and(body_json(serde_json::json!({"items": synonym_items})))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "name": "brands_products_staging",
+            "items": synonym_items,
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    // The clone references the copied synonym set and lacks the excluded field.
+    Mock::given(method("POST"))
+        .and(path("/collections"))
+        .and(body_partial_json(serde_json::json!({
+            "name": "products_staging",
+            "fields": [
+                {"name": "title", "type": "string"},
+                {"name": "country", "type": "string"},
+            ],
+            "synonym_sets": ["brands_products_staging"],
+        })))
+        .respond_with(
+            ResponseTemplate::new(201).set_body_json(CollectionResponse {
+                name: "products_staging".to_owned(),
+                ..Default::default()
+            }),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/collections/products/documents/export"))
+        .and(query_param("filter_by", "country:=US"))
+        .and(query_param("exclude_fields", "embedding"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_string(
+                [
+                    r#"{"id":"1","title":"TV","country":"US"}"#,
+                    r#"{"id":"2","title":"Radio","country":"US"}"#,
+                ]
+                .join("\n"),
+            ),
+        )
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/collections/products_staging/documents/import"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_string(
+                [
+                    r#"{"success":true}"#,
+                    r#"{"success":false,"code":400,"error":"Bad document."}"#,
+                ]
+                .join("\n"),
+            ),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let options = CollectionCloneOptions::builder()
+        .filter_by("country:=US")
+        .exclude_fields(vec!["embedding".to_owned()])
+        .build();
+    let report = client
+        .collection_schemaless("products")
+        .clone_to("products_staging", options)
+        .await
+        .expect("Clone failed");
+
+    assert_eq!(report.collection.name, "products_staging");
+    assert_eq!(report.synonym_sets, ["brands_products_staging"]);
+    assert_eq!(report.documents, 1);
+    assert_eq!(report.failed, 1);
+    assert_eq!(report.first_error.as_deref(), Some("Bad document."));
+}
+
+#[tokio::test]
+async fn test_clone_to_deletes_copied_synonym_sets_when_create_fails() {
+    let mock_server = MockServer::start().await;
+    let client = get_test_client(&mock_server.uri());
+
+    Mock::given(method("GET"))
+        .and(path("/collections/products"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(CollectionResponse {
+                name: "products".to_owned(),
+                fields: vec![field("title", "string")],
+                synonym_sets: Some(vec!["brands".to_owned()]),
+                ..Default::default()
+            }),
+        )
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/synonym_sets/brands"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "name": "brands",
+            "items": [],
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path("/synonym_sets/brands_products_staging"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "name": "brands_products_staging",
+            "items": [],
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/collections"))
+        .respond_with(ResponseTemplate::new(409).set_body_json(
+            serde_json::json!({"message": "A collection with name `products_staging` already exists."}),
+        ))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/synonym_sets/brands_products_staging"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "name": "brands_products_staging",
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let err = client
+        .collection_schemaless("products")
+        .clone_to("products_staging", CollectionCloneOptions::default())
+        .await
+        .expect_err("Clone should fail");
+    assert!(matches!(err, CloneError::CreateCollection(_)));
+}
+
+#[tokio::test]
+async fn test_clone_to_deletes_the_new_collection_when_the_import_fails() {
+    let mock_server = MockServer::start().await;
+    let client = get_test_client(&mock_server.uri());
+
+    Mock::given(method("GET"))
+        .and(path("/collections/products"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(CollectionResponse {
+                name: "products".to_owned(),
+                fields: vec![field("title", "string")],
+                synonym_sets: Some(vec!["brands".to_owned()]),
+                ..Default::default()
+            }),
+        )
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/synonym_sets/brands"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "name": "brands",
+            "items": [],
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path("/synonym_sets/brands_products_staging"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "name": "brands_products_staging",
+            "items": [],
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/collections"))
+        .respond_with(
+            ResponseTemplate::new(201).set_body_json(CollectionResponse {
+                name: "products_staging".to_owned(),
+                ..Default::default()
+            }),
+        )
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/collections/products/documents/export"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(r#"{"id":"1","title":"TV"}"#))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/collections/products_staging/documents/import"))
+        .respond_with(
+            ResponseTemplate::new(400).set_body_json(serde_json::json!({"message": "Bad JSONL."})),
+        )
+        .mount(&mock_server)
+        .await;
+
+    // Both the half-filled collection and the copied synonym set are removed again.
+    Mock::given(method("DELETE"))
+        .and(path("/collections/products_staging"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(CollectionResponse {
+                name: "products_staging".to_owned(),
+                ..Default::default()
+            }),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/synonym_sets/brands_products_staging"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "name": "brands_products_staging",
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let err = client
+        .collection_schemaless("products")
+        .clone_to("products_staging", CollectionCloneOptions::default())
+        .await
+        .expect_err("Clone should fail");
+    assert!(matches!(err, CloneError::Import(_)));
+}
@@ -0,0 +1,303 @@
+#![cfg(all(not(target_family = "wasm"), feature = "csv"))]
+
+use super::get_test_client;
+use typesense::{
+    CsvImportError,
+    models::{CsvGeopointColumns, CsvImportOptions, Field},
+};
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{method, path},
+};
+
+fn fields() -> Vec<Field> {
+    vec![
+        Field::builder().name("title").r#type("string").build(),
+        Field::builder().name("pages").r#type("int32").build(),
+        Field::builder().name("rating").r#type("float").build(),
+        Field::builder().name("in_stock").r#type("bool").build(),
+        Field::builder().name("tags").r#type("string[]").build(),
+        Field::builder().name("editions").r#type("int64[]").build(),
+        Field::builder().name("store").r#type("geopoint").build(),
+        Field::builder()
+            .name("origin")
+            .r#type("geopoint")
+            .optional(true)
+            .build(),
+    ]
+}
+
+#[test]
+fn test_csv_coerces_columns_to_field_types() {
+    let csv = "\
+title,pages,rating,in_stock,tags,editions,store,lat,lng,note
+Dune,412,4.5,TRUE,scifi|classic,1965|2005,\"48.85,2.35\",10.5,-20.25,first
+Emma,,3,no,,,,,,
+";
+    let options = CsvImportOptions::builder()
+        .geopoints(vec![CsvGeopointColumns::new("origin", "lat", "lng")])
+        .build();
+    let jsonl = options.to_jsonl(csv.as_bytes(), &fields()).unwrap();
+    let documents = jsonl
+        .lines()
+        .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        documents,
+        vec![
+            serde_json::json!({
+                "title": "Dune",
+                "pages": 412,
+                "rating": 4.5,
+                "in_stock": true,
+                "tags": ["scifi", "classic"],
+                "editions": [1965, 2005],
+                "store": [48.85, 2.35],
+                "origin": [10.5, -20.25],
+                // Columns without a field are kept as strings.
+                "note": "first",
+            }),
+            // Empty cells are left out.
+            serde_json::json!({ "title": "Emma", "rating": 3.0, "in_stock": false }),
+        ]
+    );
+}
+
+#[test]
+fn test_tsv_and_custom_array_delimiter() {
+    let tsv = "title\ttags\nDune\tscifi,classic\n";
+    let options = CsvImportOptions::builder()
+        .delimiter(b'\t')
+        .array_delimiter(',')
+        .build();
+    let jsonl = options.to_jsonl(tsv.as_bytes(), &fields()).unwrap();
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&jsonl).unwrap(),
+        serde_json::json!({ "title": "Dune", "tags": ["scifi", "classic"] })
+    );
+}
+
+#[test]
+fn test_csv_trims_only_typed_cells() {
+    // String cells keep their whitespace, numbers, bools and geopoints are trimmed.
+    let csv =
+        "title,pages,in_stock,editions,store\n  Dune , 412 , yes ,1965 | 2005,\" 48.85, 2.35 \"\n";
+    let jsonl = CsvImportOptions::default()
+        .to_jsonl(csv.as_bytes(), &fields())
+        .unwrap();
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&jsonl).unwrap(),
+        serde_json::json!({
+            "title": "  Dune ",
+            "pages": 412,
+            "in_stock": true,
+            "editions": [1965, 2005],
+            "store": [48.85, 2.35],
+        })
+    );
+}
+
+#[test]
+fn test_csv_errors_report_the_row() {
+    let csv = "title,pages,tags\nDune,412,a\nEmma,many,b\n";
+    let err = CsvImportOptions::default()
+        .to_jsonl(csv.as_bytes(), &fields())
+        .unwrap_err();
+    match err {
+        CsvImportError::InvalidValue {
+            row,
+            column,
+            value,
+            expected,
+            ..
+        } => {
+            assert_eq!(row, 3);
+            assert_eq!(column, "pages");
+            assert_eq!(value, "many");
+            assert_eq!(expected, "int32");
+        }
+        other => panic!("Unexpected error: {other:?}"),
+    }
+
+    let csv = "title,pages\nDune,412\nEmma\n";
+    let err = CsvImportOptions::default()
+        .to_jsonl(csv.as_bytes(), &fields())
+        .unwrap_err();
+    assert!(matches!(err, CsvImportError::Csv { row: 3, .. }), "{err:?}");
+
+    let options = CsvImportOptions::builder()
+        .geopoints(vec![CsvGeopointColumns::new("origin", "lat", "lng")])
+        .build();
+    let err = options
+        .to_jsonl("title,lat\nDune,1\n".as_bytes(), &fields())
+        .unwrap_err();
+    assert!(
+        matches!(&err, CsvImportError::MissingColumn { column } if column == "lng"),
+        "{err:?}"
+    );
+}
+
+#[tokio::test]
+async fn test_import_csv_uses_the_live_schema() {
+    let mock_server = MockServer::start().await;
+    let client = get_test_client(&mock_server.uri());
+
+    Mock::given(method("GET"))
+        .and(path("/collections/books"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "name": "books",
+            "num_documents": 0,
+            "created_at": 0,
+            "fields": [
+                { "name": "title", "type": "string" },
+                { "name": "pages", "type": "int32" },
+            ],
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/collections/books/documents/import"))
+        .respond_with(|req: &wiremock::Request| {
+            let body = String::from_utf8_lossy(&req.body);
+            let documents = body
+                .lines()
+                .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+                .collect::<Vec<_>>();
+            assert_eq!(
+                documents,
+                vec![
+                    serde_json::json!({ "title": "Dune", "pages": 412 }),
+                    serde_json::json!({ "title": "Emma", "pages": 320 }),
+                ]
+            );
+            ResponseTemplate::new(200).set_body_string("{\"success\":true}\n{\"success\":true}")
+        })
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let report = client
+        .collection_schemaless("books")
+        .documents()
+        .import_csv(
+            "title,pages\nDune,412\nEmma,320\n".as_bytes(),
+            CsvImportOptions::default(),
+        )
+        .await
+        .expect("CSV import failed");
+    assert_eq!(report.rows(), 2);
+    assert_eq!(report.batches.len(), 1);
+    assert_eq!(report.batches[0].response.lines().count(), 2);
+}
+
+#[tokio::test]
+async fn test_import_csv_sends_batches() {
+    let mock_server = MockServer::start().await;
+    let client = get_test_client(&mock_server.uri());
+
+    Mock::given(method("POST"))
+        .and(path("/collections/books/documents/import"))
+        .respond_with(|req: &wiremock::Request| {
+            let lines = String::from_utf8_lossy(&req.body).lines().count();
+            ResponseTemplate::new(200)
+                .set_body_string(vec![r#"{"success":true}"#; lines].join("\n"))
+        })
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    let options = CsvImportOptions::builder()
+        .fields(fields())
+        .batch_size(2)
+        .build();
+    let report = client
+        .collection_schemaless("books")
+        .documents()
+        .import_csv(
+            "title,pages\nDune,412\nEmma,320\nUlysses,730\n".as_bytes(),
+            options,
+        )
+        .await
+        .expect("CSV import failed");
+
+    assert_eq!(report.rows(), 3);
+    let rows = report
+        .batches
+        .iter()
+        .map(|batch| (batch.rows.clone(), batch.documents))
+        .collect::<Vec<_>>();
+    assert_eq!(rows, [(2..=3, 2), (4..=4, 1)]);
+}
+
+#[tokio::test]
+async fn test_import_csv_stops_at_an_invalid_row() {
+    let mock_server = MockServer::start().await;
+    let client = get_test_client(&mock_server.uri());
+
+    // The first batch is imported before the invalid row of the second one is read.
+    Mock::given(method("POST"))
+        .and(path("/collections/books/documents/import"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(r#"{"success":true}"#))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let options = CsvImportOptions::builder()
+        .fields(fields())
+        .batch_size(1)
+        .build();
+    let err = client
+        .collection_schemaless("books")
+        .documents()
+        .import_csv("title,pages\nDune,412\nEmma,many\n".as_bytes(), options)
+        .await
+        .expect_err("CSV import should fail");
+    assert!(matches!(err, CsvImportError::InvalidValue { row: 3, .. }));
+    // The error keeps the batch that was imported before the invalid row.
+    let report = err.report().expect("The error should have a report");
+    assert_eq!(report.rows(), 1);
+    assert_eq!(report.batches[0].rows, 2..=2);
+}
+
+#[tokio::test]
+async fn test_import_csv_error_keeps_the_imported_batches() {
+    let mock_server = MockServer::start().await;
+    let client = get_test_client(&mock_server.uri());
+
+    Mock::given(method("POST"))
+        .and(path("/collections/books/documents/import"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(r#"{"success":true}"#))
+        .up_to_n_times(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/collections/books/documents/import"))
+        .respond_with(
+            ResponseTemplate::new(400).set_body_json(serde_json::json!({"message": "Bad JSONL."})),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let options = CsvImportOptions::builder()
+        .fields(fields())
+        .batch_size(1)
+        .build();
+    let err = client
+        .collection_schemaless("books")
+        .documents()
+        .import_csv("title,pages\nDune,412\nEmma,320\n".as_bytes(), options)
+        .await
+        .expect_err("CSV import should fail");
+    match &err {
+        CsvImportError::Import { rows, report, .. } => {
+            assert_eq!(*rows, 3..=3);
+            assert_eq!(report.rows(), 1);
+            assert_eq!(report.batches[0].rows, 2..=2);
+        }
+        other => panic!("Unexpected error: {other:?}"),
+    }
+}
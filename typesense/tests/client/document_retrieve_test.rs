@@ -0,0 +1,87 @@
+#![cfg(not(target_family = "wasm"))]
+
+use super::get_test_client;
+use serde::{Deserialize, Serialize};
+use typesense::{Typesense, models::DocumentRetrieveParameters};
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{method, path, query_param},
+};
+
+#[derive(Typesense, Serialize, Deserialize, Debug)]
+#[typesense(collection_name = "books")]
+struct Book {
+    id: String,
+    title: String,
+    pages: i32,
+    embedding: Vec<f32>,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct BookTitle {
+    title: String,
+}
+
+#[tokio::test]
+async fn test_retrieve_with_projection_type() {
+    let mock_server = MockServer::start().await;
+    let client = get_test_client(&mock_server.uri());
+
+    Mock::given(method("GET"))
+        .and(path("/collections/books/documents/1"))
+        .and(query_param("include_fields", "title"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({"title": "Dune"})),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let params = DocumentRetrieveParameters::builder()
+        .include_fields("title")
+        .build();
+    let book: BookTitle = client
+        .collection::<Book>()
+        .document("1")
+        .retrieve_with(params)
+        .await
+        .expect("Failed to retrieve projection");
+
+    assert_eq!(
+        book,
+        BookTitle {
+            title: "Dune".to_owned()
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_retrieve_partial_excludes_fields() {
+    let mock_server = MockServer::start().await;
+    let client = get_test_client(&mock_server.uri());
+
+    Mock::given(method("GET"))
+        .and(path("/collections/books/documents/1"))
+        .and(query_param("exclude_fields", "embedding"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!({"id": "1", "title": "Dune", "pages": 412})),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let params = DocumentRetrieveParameters::builder()
+        .exclude_fields("embedding")
+        .build();
+    let book = client
+        .collection::<Book>()
+        .document("1")
+        .retrieve_partial(params)
+        .await
+        .expect("Failed to retrieve partial document");
+
+    assert_eq!(book.title.as_deref(), Some("Dune"));
+    assert_eq!(book.pages, Some(412));
+    assert!(book.embedding.is_none());
+}
@@ -0,0 +1,215 @@
+#![cfg(not(target_family = "wasm"))]
+
+use super::get_test_client;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use typesense::{
+    DocumentValidationError, Typesense,
+    models::{
+        CollectionResponse, DocumentValidator, Field, ImportDocumentsParameters,
+        ImportLineErrorKind, ImportRetryOptions, IndexAction,
+    },
+    prelude::Document,
+};
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{body_string, method, path},
+};
+
+#[derive(Typesense, Serialize, Deserialize)]
+#[typesense(collection_name = "places")]
+struct Place {
+    id: String,
+    name: String,
+    #[typesense(optional)]
+    visitors: Option<i32>,
+    tags: Vec<String>,
+}
+
+fn field(name: &str, r#type: &str) -> Field {
+    Field {
+        name: name.to_owned(),
+        r#type: r#type.to_owned(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_validator_reports_every_violation() {
+    let collection = CollectionResponse {
+        name: "places".to_owned(),
+        fields: vec![
+            field("name", "string"),
+            field("visitors", "int32"),
+            field("location", "geopoint"),
+            field("address.city", "string"),
+            Field {
+                num_dim: Some(3),
+                ..field("embedding", "float[]")
+            },
+            Field {
+                optional: Some(true),
+                ..field("area", "geopolygon")
+            },
+            field(".*_facet", "auto"),
+        ],
+        ..Default::default()
+    };
+    let validator = DocumentValidator::from(&collection);
+
+    let valid = json!({
+        "name": "Louvre",
+        "visitors": 8_700_000,
+        "location": [48.86, 2.34],
+        "address": {"city": "Paris"},
+        "embedding": [0.1, 0.2, 0.3],
+        "area": [48.86, 2.33, 48.87, 2.34, 48.86, 2.35],
+    });
+    assert_eq!(validator.validate(&valid), Ok(()));
+
+    let invalid = json!({
+        "visitors": 5_000_000_000_i64,
+        "location": [148.86, 2.34],
+        "address": {"city": 75},
+        "embedding": [0.1, 0.2],
+        "area": [48.86, 2.33],
+    });
+    let errors = validator.validate(&invalid).unwrap_err();
+    assert_eq!(
+        errors,
+        [
+            DocumentValidationError::MissingField {
+                field: "name".to_owned()
+            },
+            DocumentValidationError::TypeMismatch {
+                field: "visitors".to_owned(),
+                expected: "int32".to_owned(),
+                found: "an integer",
+            },
+            DocumentValidationError::InvalidGeo {
+                field: "location".to_owned(),
+                expected: "geopoint".to_owned(),
+                reason: "latitude must be between -90 and 90",
+            },
+            DocumentValidationError::TypeMismatch {
+                field: "address.city".to_owned(),
+                expected: "string".to_owned(),
+                found: "an integer",
+            },
+            DocumentValidationError::VectorDimensions {
+                field: "embedding".to_owned(),
+                expected: 3,
+                found: 2,
+            },
+            DocumentValidationError::InvalidGeo {
+                field: "area".to_owned(),
+                expected: "geopolygon".to_owned(),
+                reason: "expected at least three [latitude, longitude] points",
+            },
+        ]
+    );
+
+    // Partial documents are only checked for the fields they contain.
+    assert_eq!(validator.validate_partial(&json!({"visitors": 12})), Ok(()));
+}
+
+#[test]
+fn test_validator_checks_typed_documents() {
+    let validator = DocumentValidator::from(&Place::collection_schema());
+
+    let place = Place {
+        id: "1".to_owned(),
+        name: "Louvre".to_owned(),
+        visitors: None,
+        tags: vec!["museum".to_owned()],
+    };
+    assert_eq!(validator.validate_document(&place), Ok(()));
+    assert_eq!(
+        validator.validate(&json!({"id": "1", "name": "Louvre", "tags": ["museum", 1]})),
+        Err(vec![DocumentValidationError::TypeMismatch {
+            field: "tags".to_owned(),
+            expected: "string[]".to_owned(),
+            found: "an integer",
+        }])
+    );
+}
+
+#[test]
+fn test_validator_checks_emplace_as_full_document() {
+    let validator = DocumentValidator::from(&Place::collection_schema());
+    let document = json!({"id": "1", "tags": []});
+
+    // `emplace` creates missing documents, so required fields are checked; `update` never does.
+    assert_eq!(
+        validator.validate_for_action(&document, Some(&IndexAction::Emplace)),
+        Err(vec![DocumentValidationError::MissingField {
+            field: "name".to_owned()
+        }])
+    );
+    assert_eq!(
+        validator.validate_for_action(&document, Some(&IndexAction::Update)),
+        Ok(())
+    );
+}
+
+#[test]
+fn test_validator_splits_jsonl_lines() {
+    let validator = DocumentValidator::from(&Place::collection_schema());
+    let jsonl = "{\"id\":\"1\",\"name\":\"Louvre\",\"tags\":[]}\n\n{\"id\":\"2\",\"tags\":[]}\n{\"id\":\"3\",\"name\":\"Orsay\",\"tags\":[]}";
+    let validation = validator.validate_jsonl(jsonl, Some(&IndexAction::Emplace));
+
+    assert_eq!(validation.valid.len(), 2);
+    assert_eq!(
+        validation.valid_jsonl(),
+        "{\"id\":\"1\",\"name\":\"Louvre\",\"tags\":[]}\n{\"id\":\"3\",\"name\":\"Orsay\",\"tags\":[]}"
+    );
+    assert_eq!(validation.invalid.len(), 1);
+    assert_eq!(validation.invalid[0].line, 3);
+    assert_eq!(validation.invalid[0].document, "{\"id\":\"2\",\"tags\":[]}");
+}
+
+#[tokio::test]
+async fn test_import_with_validator_dead_letters_invalid_lines() {
+    let mock_server = MockServer::start().await;
+    let client = get_test_client(&mock_server.uri());
+
+    let valid = r#"{"id":"1","name":"Louvre","tags":[]}"#;
+    let invalid = r#"{"id":"2","tags":[]}"#;
+
+    // Only the valid line is sent.
+    Mock::given(method("POST"))
+        .and(path("/collections/places/documents/import"))
+        .and(body_string(valid))
+        .respond_with(ResponseTemplate::new(200).set_body_string(r#"{"success":true}"#))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let options = ImportRetryOptions::builder()
+        .validator(DocumentValidator::from(&Place::collection_schema()))
+        .build();
+    let mut dead_letters = Vec::new();
+    let report = client
+        .collection::<Place>()
+        .documents()
+        .import_jsonl_with_retry(
+            format!("{valid}\n{invalid}\nnot json"),
+            ImportDocumentsParameters::default(),
+            options,
+            &mut dead_letters,
+        )
+        .await
+        .expect("Import with retry failed");
+
+    assert_eq!(report.imported, 1);
+    assert_eq!(report.dead_lettered, 2);
+    assert_eq!(report.requests, 1);
+    assert_eq!(dead_letters[0].document, invalid);
+    assert_eq!(
+        dead_letters[0].error,
+        "Field `name` is required but missing"
+    );
+    assert_eq!(dead_letters[0].kind, ImportLineErrorKind::Permanent);
+    assert_eq!(dead_letters[0].attempts, 0);
+    assert_eq!(dead_letters[1].document, "not json");
+}
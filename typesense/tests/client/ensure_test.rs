@@ -0,0 +1,264 @@
+#![cfg(not(target_family = "wasm"))]
+
+use super::get_test_client;
+use serde::{Deserialize, Serialize};
+use std::{
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+use typesense::{
+    EnsureError, Typesense,
+    models::{
+        CollectionResponse, EnsureOutcome, EnsurePolicy, Field, ReindexOptions,
+        SchemaChangeWaitOptions,
+    },
+};
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{method, path, path_regex},
+};
+
+#[derive(Typesense, Serialize, Deserialize)]
+#[typesense(collection_name = "companies")]
+struct Company {
+    id: String,
+    company_name: String,
+    #[typesense(facet)]
+    country: String,
+}
+
+/// The live `companies` collection, which lacks the `country` field.
+fn outdated_collection() -> CollectionResponse {
+    CollectionResponse {
+        name: "companies".to_owned(),
+        fields: vec![Field {
+            name: "company_name".to_owned(),
+            r#type: "string".to_owned(),
+            ..Default::default()
+        }],
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_ensure_creates_missing_collection() {
+    let mock_server = MockServer::start().await;
+    let client = get_test_client(&mock_server.uri());
+
+    Mock::given(method("GET"))
+        .and(path("/collections/companies"))
+        .respond_with(
+            ResponseTemplate::new(404).set_body_json(serde_json::json!({"message": "Not Found"})),
+        )
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/collections"))
+        .respond_with(
+            ResponseTemplate::new(201).set_body_json(CollectionResponse {
+                name: "companies".to_owned(),
+                ..Default::default()
+            }),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let outcome = client
+        .collection::<Company>()
+        .ensure(EnsurePolicy::FailOnDrift)
+        .await
+        .expect("Ensure failed");
+
+    assert!(matches!(outcome, EnsureOutcome::Created(c) if c.name == "companies"));
+}
+
+#[tokio::test]
+async fn test_ensure_updates_in_place() {
+    let mock_server = MockServer::start().await;
+    let client = get_test_client(&mock_server.uri());
+
+    Mock::given(method("GET"))
+        .and(path("/collections/companies"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(outdated_collection()))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PATCH"))
+        .and(path("/collections/companies"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({"fields": []})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    // `ensure` returns only once the alter is no longer reported as running.
+    let polls = AtomicUsize::new(0);
+    Mock::given(method("GET"))
+        .and(path("/operations/schema_changes"))
+        .respond_with(move |_: &wiremock::Request| {
+            let body = match polls.fetch_add(1, Ordering::SeqCst) {
+                0 => serde_json::json!([{"collection": "companies", "validated_docs": 10}]),
+                _ => serde_json::json!([]),
+            };
+            ResponseTemplate::new(200).set_body_json(body)
+        })
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    let options = SchemaChangeWaitOptions::builder()
+        .poll_interval(Duration::from_millis(1))
+        .build();
+    let outcome = client
+        .collection::<Company>()
+        .ensure(EnsurePolicy::UpdateInPlace(options))
+        .await
+        .expect("Ensure failed");
+
+    match outcome {
+        EnsureOutcome::Updated(diff) => {
+            assert_eq!(diff.added.len(), 1);
+            assert_eq!(diff.added[0].name, "country");
+        }
+        other => panic!("Unexpected outcome: {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_ensure_fails_on_drift() {
+    let mock_server = MockServer::start().await;
+    let client = get_test_client(&mock_server.uri());
+
+    Mock::given(method("GET"))
+        .and(path("/collections/companies"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(outdated_collection()))
+        .mount(&mock_server)
+        .await;
+
+    let err = client
+        .collection::<Company>()
+        .ensure(EnsurePolicy::FailOnDrift)
+        .await
+        .expect_err("Ensure should fail on drift");
+    assert!(matches!(err, EnsureError::Drift(diff) if diff.added.len() == 1));
+
+    let outcome = client
+        .collection::<Company>()
+        .ensure(EnsurePolicy::CreateOnly)
+        .await
+        .expect("Ensure failed");
+    assert_eq!(outcome, EnsureOutcome::Exists);
+}
+
+#[tokio::test]
+async fn test_ensure_reindex_rejects_plain_collection() {
+    let mock_server = MockServer::start().await;
+    let client = get_test_client(&mock_server.uri());
+
+    Mock::given(method("GET"))
+        .and(path("/collections/companies"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(outdated_collection()))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/aliases/companies"))
+        .respond_with(
+            ResponseTemplate::new(404).set_body_json(serde_json::json!({"message": "Not Found"})),
+        )
+        .mount(&mock_server)
+        .await;
+    // Nothing is created before the missing alias is noticed.
+    Mock::given(method("POST"))
+        .and(path("/collections"))
+        .respond_with(ResponseTemplate::new(201))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let err = client
+        .collection::<Company>()
+        .ensure(EnsurePolicy::Reindex(ReindexOptions::default()))
+        .await
+        .expect_err("Ensure should reject a plain collection");
+    assert!(matches!(err, EnsureError::NotAnAlias(name) if name == "companies"));
+}
+
+#[tokio::test]
+async fn test_ensure_reindex_skips_taken_versioned_names() {
+    let mock_server = MockServer::start().await;
+    let client = get_test_client(&mock_server.uri());
+
+    Mock::given(method("GET"))
+        .and(path("/collections/companies"))
+        .respond_with(
+            ResponseTemplate::new(404).set_body_json(serde_json::json!({"message": "Not Found"})),
+        )
+        .mount(&mock_server)
+        .await;
+    // The first versioned name is already taken, e.g. by an `ensure` in the same millisecond.
+    Mock::given(method("GET"))
+        .and(path_regex(r"^/collections/companies_\d+$"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(outdated_collection()))
+        .up_to_n_times(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path_regex(r"^/collections/companies_\d+$"))
+        .respond_with(
+            ResponseTemplate::new(404).set_body_json(serde_json::json!({"message": "Not Found"})),
+        )
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/collections"))
+        .respond_with(|req: &wiremock::Request| {
+            let schema: serde_json::Value = req.body_json().expect("JSON schema");
+            ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "name": schema["name"],
+                "fields": [],
+                "num_documents": 0,
+                "created_at": 0,
+            }))
+        })
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path("/aliases/companies"))
+        .respond_with(|req: &wiremock::Request| {
+            let alias: serde_json::Value = req.body_json().expect("JSON alias");
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "name": "companies",
+                "collection_name": alias["collection_name"],
+            }))
+        })
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let outcome = client
+        .collection::<Company>()
+        .ensure(EnsurePolicy::Reindex(ReindexOptions::default()))
+        .await
+        .expect("Ensure failed");
+
+    let lookups: Vec<String> = mock_server
+        .received_requests()
+        .await
+        .expect("Requests are recorded")
+        .into_iter()
+        .filter(|req| {
+            req.method.as_str() == "GET" && req.url.path().starts_with("/collections/companies_")
+        })
+        .map(|req| {
+            req.url
+                .path()
+                .trim_start_matches("/collections/")
+                .to_owned()
+        })
+        .collect();
+    assert_eq!(lookups.len(), 2);
+    assert_ne!(lookups[0], lookups[1]);
+    match outcome {
+        EnsureOutcome::Created(created) => assert_eq!(created.name, lookups[1]),
+        other => panic!("Unexpected outcome: {other:?}"),
+    }
+}
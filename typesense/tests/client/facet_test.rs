@@ -0,0 +1,174 @@
+#![cfg(not(target_family = "wasm"))]
+
+use serde::Deserialize;
+use serde_json::json;
+use typesense::{
+    FacetSpecError,
+    models::{FacetBy, FacetSpec, FacetStats, SearchParameters, SearchResult, SortOrder},
+    prelude::*,
+};
+
+#[test]
+fn test_facet_by_specs() {
+    let facet_by = FacetBy::new()
+        .field("brand")
+        .spec(
+            FacetSpec::new("rating")
+                .range("average", 0.0, 3.0)
+                .and_then(|spec| spec.range("good", 3.0, 4.5))
+                .and_then(|spec| spec.range_from("great", 4.5))
+                .unwrap(),
+        )
+        .spec(FacetSpec::new("category").sort_by_field("popularity", SortOrder::Desc));
+    assert_eq!(
+        facet_by.to_string(),
+        "brand,rating(average:[0, 3], good:[3, 4.5], great:[4.5, ]),\
+         category(sort_by: popularity:desc)"
+    );
+
+    let params = SearchParameters::builder()
+        .q("*")
+        .facet_by(facet_by)
+        .build();
+    assert!(params.facet_by.unwrap().starts_with("brand,rating("));
+}
+
+#[test]
+fn test_facet_range_labels_are_validated() {
+    let spec = FacetSpec::new("price")
+        .range("under_10-eur", 0.0, 10.0)
+        .unwrap();
+    assert_eq!(spec.to_string(), "price(under_10-eur:[0, 10])");
+
+    for label in [
+        "",
+        "cheap, good:[0, 1]",
+        "a)b",
+        "a:b",
+        "two words",
+        "`x`",
+        "prix€",
+    ] {
+        assert_eq!(
+            FacetSpec::new("price").range(label, 0.0, 10.0),
+            Err(FacetSpecError::InvalidRangeLabel(label.to_owned())),
+        );
+        assert_eq!(
+            FacetSpec::new("price").range_from(label, 0.0),
+            Err(FacetSpecError::InvalidRangeLabel(label.to_owned())),
+        );
+    }
+}
+
+fn search_result() -> SearchResult<serde_json::Value> {
+    serde_json::from_value(json!({
+        "found": 3,
+        "hits": [],
+        "facet_counts": [
+            {
+                "field_name": "brand",
+                "sampled": false,
+                "counts": [
+                    { "value": "Acme", "highlighted": "Acme", "count": 2 },
+                    { "value": "Globex", "highlighted": "Globex", "count": 1 }
+                ],
+                "stats": { "total_values": 2 }
+            },
+            {
+                "field_name": "price",
+                "sampled": true,
+                "counts": [
+                    { "value": "9.99", "count": 2 },
+                    { "value": "25", "count": 1 }
+                ],
+                "stats": { "min": 9.99, "max": 25.0, "avg": 14.99, "sum": 44.98, "total_values": 2 }
+            },
+            {
+                "field_name": "rating",
+                "counts": [
+                    { "value": "good", "count": 1 },
+                    { "value": "great", "count": 2 }
+                ],
+                "stats": { "total_values": 2 }
+            },
+            {
+                "field_name": "variants.color",
+                "counts": [
+                    {
+                        "value": "red",
+                        "count": 1,
+                        "parent": { "color": "red", "sku": "A-1" }
+                    }
+                ]
+            }
+        ]
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_facet_lookup_and_counts() {
+    let result = search_result();
+
+    let brand = result.facet("brand").unwrap();
+    assert_eq!(brand.count("Acme"), Some(2));
+    assert_eq!(brand.count("Initech"), None);
+    assert_eq!(brand.counts().len(), 2);
+    assert_eq!(brand.counts()["Globex"], 1);
+    assert_eq!(brand.total_values, Some(2));
+    assert_eq!(brand.stats, None);
+    assert!(!brand.sampled);
+
+    // Range facets report counts under their labels.
+    let rating = result.facet("rating").unwrap();
+    assert_eq!(rating.count("great"), Some(2));
+
+    assert!(result.facet("missing").is_none());
+    assert_eq!(
+        result
+            .facets()
+            .iter()
+            .map(|facet| facet.field_name.as_str())
+            .collect::<Vec<_>>(),
+        ["brand", "price", "rating", "variants.color"]
+    );
+}
+
+#[test]
+fn test_facet_numeric_stats() {
+    let price = search_result().facet("price").unwrap();
+    assert_eq!(
+        price.stats,
+        Some(FacetStats {
+            min: 9.99,
+            max: 25.0,
+            avg: 14.99,
+            sum: 44.98,
+        })
+    );
+    assert!(price.sampled);
+}
+
+#[test]
+fn test_facet_parent_objects() {
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Variant {
+        color: String,
+        sku: String,
+    }
+
+    let color = search_result().facet("variants.color").unwrap();
+    let parent = color.values[0].parent_as::<Variant>().unwrap().unwrap();
+    assert_eq!(
+        parent,
+        Variant {
+            color: "red".to_owned(),
+            sku: "A-1".to_owned(),
+        }
+    );
+    assert!(
+        search_result().facet("brand").unwrap().values[0]
+            .parent_as::<Variant>()
+            .is_none()
+    );
+}
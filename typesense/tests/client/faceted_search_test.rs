@@ -0,0 +1,157 @@
+#![cfg(not(target_family = "wasm"))]
+
+use super::get_test_client;
+use serde::Deserialize;
+use serde_json::json;
+use typesense::models::{FacetedSearchState, Filter, MultiSearchParameters};
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{body_partial_json, method, path},
+};
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct Product {
+    id: String,
+    title: String,
+}
+
+fn state() -> FacetedSearchState {
+    let mut state = FacetedSearchState::builder()
+        .collection("products")
+        .query("shoe")
+        .query_by("title")
+        .filter_by(Filter::field("in_stock").eq(true))
+        .disjunctive_facets(vec!["brand".to_owned(), "color".to_owned()])
+        .conjunctive_facets(vec!["tags".to_owned()])
+        .page(3)
+        .build();
+    state.toggle_refinement("brand", "Acme");
+    state.toggle_refinement("brand", "Globex");
+    state.toggle_refinement("tags", "sale");
+    state.toggle_refinement("tags", "new");
+    state.set_range("price", Some(10.0), Some(50.0));
+    state
+}
+
+#[test]
+fn test_refinements_toggle_and_reset_page() {
+    let mut state = state();
+    assert!(state.is_refined("brand", "Acme"));
+    assert_eq!(state.page, 1);
+
+    state.page = 2;
+    state.toggle_refinement("brand", "Acme");
+    assert!(!state.is_refined("brand", "Acme"));
+    assert_eq!(state.page, 1);
+
+    state.toggle_refinement("brand", "Globex");
+    assert!(!state.refinements.contains_key("brand"));
+
+    state.set_range("price", None, None);
+    assert!(state.numeric_ranges.is_empty());
+}
+
+#[test]
+fn test_multi_search_body_excludes_own_refinements() {
+    let body = state().to_multi_search_body();
+    // The hits search, and one search for the refined disjunctive facet `brand`. The
+    // conjunctive `tags` and the unrefined `color` are counted by the hits search.
+    assert_eq!(body.searches.len(), 2);
+
+    let hits = &body.searches[0];
+    assert_eq!(hits.collection.as_deref(), Some("products"));
+    assert_eq!(hits.q.as_deref(), Some("shoe"));
+    assert_eq!(hits.query_by.as_deref(), Some("title"));
+    assert_eq!(hits.facet_by.as_deref(), Some("brand,color,tags"));
+    assert_eq!(
+        hits.filter_by.as_deref(),
+        Some(
+            "in_stock:=true && price:[10..50] && brand:=[`Acme`,`Globex`] && \
+             (tags:=`sale` && tags:=`new`)"
+        )
+    );
+    assert_eq!((hits.page, hits.per_page), (Some(1), Some(10)));
+
+    let brand = &body.searches[1];
+    assert_eq!(brand.facet_by.as_deref(), Some("brand"));
+    assert_eq!(
+        brand.filter_by.as_deref(),
+        Some("in_stock:=true && price:[10..50] && (tags:=`sale` && tags:=`new`)")
+    );
+    assert_eq!(brand.per_page, Some(0));
+}
+
+#[tokio::test]
+async fn test_merge_results_replaces_disjunctive_counts() {
+    let mock_server = MockServer::start().await;
+    let client = get_test_client(&mock_server.uri());
+
+    let mut state = state();
+    state.refinements.remove("tags");
+
+    Mock::given(method("POST"))
+        .and(path("/multi_search"))
+        .and(body_partial_json(json!({
+            "searches": [{ "facet_by": "brand,color,tags" }, { "facet_by": "brand" }]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [
+                {
+                    "found": 1,
+                    "hits": [{ "document": { "id": "1", "title": "Acme shoe" } }],
+                    "facet_counts": [
+                        { "field_name": "brand", "counts": [{ "value": "Acme", "count": 1 }] },
+                        { "field_name": "color", "counts": [{ "value": "red", "count": 1 }] },
+                        { "field_name": "tags", "counts": [{ "value": "sale", "count": 1 }] }
+                    ]
+                },
+                {
+                    "found": 4,
+                    "hits": [],
+                    "facet_counts": [
+                        {
+                            "field_name": "brand",
+                            "counts": [
+                                { "value": "Initech", "count": 3 },
+                                { "value": "Acme", "count": 1 }
+                            ]
+                        }
+                    ]
+                }
+            ]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let response = client
+        .multi_search()
+        .perform(
+            state.to_multi_search_body(),
+            MultiSearchParameters::default(),
+        )
+        .await
+        .expect("Multi-search failed");
+    let result = state.merge_results::<Product>(&response).unwrap();
+
+    assert_eq!(result.result.found, Some(1));
+    assert_eq!(
+        result.result.hits.as_ref().unwrap()[0].document,
+        Some(Product {
+            id: "1".to_owned(),
+            title: "Acme shoe".to_owned(),
+        })
+    );
+    let brand = result.facet("brand").unwrap();
+    assert_eq!(brand.count("Initech"), Some(3));
+    assert_eq!(brand.count("Acme"), Some(1));
+    assert_eq!(result.facet("color").unwrap().count("red"), Some(1));
+    assert_eq!(
+        result
+            .facets
+            .iter()
+            .map(|facet| facet.field_name.as_str())
+            .collect::<Vec<_>>(),
+        ["brand", "color", "tags"]
+    );
+}
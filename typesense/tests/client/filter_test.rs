@@ -0,0 +1,138 @@
+#![cfg(not(target_family = "wasm"))]
+
+use std::borrow::Cow;
+use typesense::models::{
+    DeleteDocumentsParameters, ExportDocumentsParameters, Filter, GeoDistance, GeoPoint,
+    GeoPolygon, SearchParameters, UpdateDocumentsParameters,
+};
+
+#[test]
+fn test_filter_escapes_string_values() {
+    assert_eq!(
+        Filter::field("title")
+            .eq("Dune, Part [1] && (2)")
+            .to_string(),
+        "title:=`Dune, Part [1] && (2)`"
+    );
+    assert_eq!(
+        Filter::field("title").matches("it`s").to_string(),
+        "title:`it\\`s`"
+    );
+    assert_eq!(
+        Filter::field("tags")
+            .one_of(vec!["a,b".to_owned(), "c".to_owned()])
+            .to_string(),
+        "tags:=[`a,b`,`c`]"
+    );
+}
+
+#[test]
+fn test_filter_conditions() {
+    let cases = [
+        (Filter::field("in_stock").eq(true), "in_stock:=true"),
+        (Filter::field("brand").ne("Acme"), "brand:!=`Acme`"),
+        (Filter::field("id").none_of(["1", "2"]), "id:!=[`1`,`2`]"),
+        (Filter::field("price").gt(10), "price:>10"),
+        (Filter::field("price").gte(10.5), "price:>=10.5"),
+        (Filter::field("price").lt(-1), "price:<-1"),
+        (Filter::field("price").lte(99), "price:<=99"),
+        (
+            Filter::field("year").between(1990, 2000),
+            "year:[1990..2000]",
+        ),
+        (
+            Filter::field("location")
+                .within_radius(GeoPoint::new(48.85, 2.29), GeoDistance::Miles(2.5)),
+            "location:(48.85, 2.29, 2.5 mi)",
+        ),
+        (
+            Filter::field("location").within_polygon(
+                &GeoPolygon::new([(48.87, 2.28), (48.87, 2.35), (48.85, 2.35)]).unwrap(),
+            ),
+            "location:(48.87, 2.28, 48.87, 2.35, 48.85, 2.35)",
+        ),
+        (
+            Filter::not_join("Orders", Filter::field("status").eq("open")),
+            "!$Orders(status:=`open`)",
+        ),
+    ];
+    for (filter, expected) in cases {
+        assert_eq!(filter.to_string(), expected);
+    }
+}
+
+#[test]
+fn test_filter_grouping() {
+    let a = || Filter::field("a").eq(1);
+    let b = || Filter::field("b").eq(2);
+    let c = || Filter::field("c").eq(3);
+
+    assert_eq!(a().and(b()).and(c()).to_string(), "a:=1 && b:=2 && c:=3");
+    assert_eq!(a().or(b()).and(c()).to_string(), "(a:=1 || b:=2) && c:=3");
+    assert_eq!(a().and(b().or(c())).to_string(), "a:=1 && (b:=2 || c:=3)");
+    assert_eq!(
+        Filter::any([a().and(b()), c()]).to_string(),
+        "(a:=1 && b:=2) || c:=3"
+    );
+    assert_eq!(
+        Filter::raw("x:1 || y:2").and(a()).to_string(),
+        "(x:1 || y:2) && a:=1"
+    );
+
+    // Empty groups are left out.
+    assert!(Filter::all([]).is_empty());
+    assert_eq!(Filter::all([Filter::any([]), a()]).to_string(), "a:=1");
+    assert_eq!(
+        Filter::join("Customers", a().or(b())).to_string(),
+        "$Customers(a:=1 || b:=2)"
+    );
+}
+
+#[test]
+fn test_filter_empty_lists() {
+    let a = || Filter::field("a").eq(1);
+    let never = || Filter::field("tags").one_of(Vec::<String>::new());
+    let always = || Filter::field("tags").none_of(Vec::<String>::new());
+
+    // `one_of` nothing matches no document.
+    assert_eq!(never().to_string(), "id:=`0` && id:!=`0`");
+    assert!(!never().is_empty());
+    assert_eq!(a().and(never()).to_string(), "id:=`0` && id:!=`0`");
+    assert_eq!(a().or(never()).to_string(), "a:=1");
+
+    // `none_of` nothing matches every document.
+    assert_eq!(always().to_string(), "");
+    assert!(always().is_empty());
+    assert_eq!(a().and(always()).to_string(), "a:=1");
+    assert_eq!(a().or(always()).to_string(), "");
+    assert_eq!(
+        Filter::field("b").eq(2).and(a().or(always())).to_string(),
+        "b:=2"
+    );
+}
+
+#[test]
+fn test_filter_plugs_into_parameters() {
+    let filter = || Filter::field("country").eq("US");
+
+    let search = SearchParameters::builder()
+        .q("*")
+        .filter_by(filter())
+        .build();
+    assert_eq!(search.filter_by.as_deref(), Some("country:=`US`"));
+
+    let delete = DeleteDocumentsParameters::new(filter().into());
+    assert_eq!(delete.filter_by, "country:=`US`");
+
+    let export = ExportDocumentsParameters {
+        filter_by: Some(filter().into()),
+        ..Default::default()
+    };
+    assert_eq!(export.filter_by, Some(Cow::from("country:=`US`")));
+
+    let update = UpdateDocumentsParameters {
+        filter_by: Some(filter().into()),
+        ..Default::default()
+    };
+    assert_eq!(update.filter_by.as_deref(), Some("country:=`US`"));
+}
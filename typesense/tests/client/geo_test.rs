@@ -0,0 +1,71 @@
+#![cfg(not(target_family = "wasm"))]
+
+use serde_json::json;
+use typesense::{
+    GeoPolygonError,
+    models::{GeoPoint, GeoPolygon, SearchResultHit},
+    prelude::*,
+};
+
+#[test]
+fn test_geo_point_serde() {
+    let point = GeoPoint::new(48.8583, 2.2945);
+    assert_eq!(
+        serde_json::to_value(point).unwrap(),
+        json!([48.8583, 2.2945])
+    );
+    assert_eq!(
+        serde_json::from_value::<GeoPoint>(json!([48.8583, 2.2945])).unwrap(),
+        point
+    );
+    assert_eq!(
+        serde_json::from_value::<Vec<GeoPoint>>(json!([[1.0, 2.0], [3.0, 4.0]])).unwrap(),
+        [GeoPoint::new(1.0, 2.0), GeoPoint::new(3.0, 4.0)]
+    );
+    assert!(serde_json::from_value::<GeoPoint>(json!([48.8583])).is_err());
+    assert_eq!(<[f64; 2]>::from(point), [48.8583, 2.2945]);
+}
+
+#[test]
+fn test_geo_polygon_serde() {
+    let polygon = GeoPolygon::new([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]).unwrap();
+    assert_eq!(
+        serde_json::to_value(&polygon).unwrap(),
+        json!([1.0, 2.0, 3.0, 4.0, 5.0, 6.0])
+    );
+    assert_eq!(
+        serde_json::from_value::<GeoPolygon>(json!([1.0, 2.0, 3.0, 4.0, 5.0, 6.0])).unwrap(),
+        polygon
+    );
+    assert!(serde_json::from_value::<GeoPolygon>(json!([1.0, 2.0, 3.0, 4.0, 5.0])).is_err());
+
+    assert_eq!(
+        GeoPolygon::new([(1.0, 2.0), (3.0, 4.0)]),
+        Err(GeoPolygonError::TooFewVertices(2))
+    );
+    assert_eq!(
+        GeoPolygon::try_from(vec![1.0, 2.0, 3.0]),
+        Err(GeoPolygonError::UnpairedCoordinate)
+    );
+}
+
+#[test]
+fn test_geo_field_types() {
+    assert_eq!(GeoPoint::to_typesense_type(), "geopoint");
+    assert_eq!(GeoPolygon::to_typesense_type(), "geopolygon");
+}
+
+#[test]
+fn test_hit_geo_distance_meters() {
+    let hit: SearchResultHit<serde_json::Value> = serde_json::from_value(json!({
+        "document": { "location": [48.86, 2.3] },
+        "geo_distance_meters": { "location": 1240 }
+    }))
+    .unwrap();
+    assert_eq!(hit.geo_distance_meters("location"), Some(1240));
+    assert_eq!(hit.geo_distance_meters("origin"), None);
+
+    let hit: SearchResultHit<serde_json::Value> =
+        serde_json::from_value(json!({ "document": {} })).unwrap();
+    assert_eq!(hit.geo_distance_meters("location"), None);
+}
@@ -0,0 +1,100 @@
+#![cfg(not(target_family = "wasm"))]
+
+use super::get_test_client;
+use serde::Deserialize;
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{method, path, query_param},
+};
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct Book {
+    id: String,
+    title: String,
+}
+
+#[tokio::test]
+async fn test_get_many_preserves_order_and_reports_missing() {
+    let mock_server = MockServer::start().await;
+    let client = get_test_client(&mock_server.uri());
+
+    // The export returns documents in index order, not in the requested order.
+    Mock::given(method("GET"))
+        .and(path("/collections/books/documents/export"))
+        .and(query_param("filter_by", "id:=[`3`,`1`,`2`]"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_string(
+                [
+                    r#"{"id":"1","title":"Dune"}"#,
+                    r#"{"id":"3","title":"Emma"}"#,
+                ]
+                .join("\n"),
+            ),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let result = client
+        .collection_named::<Book>("books")
+        .documents()
+        .get_many(["3", "1", "3", "2"])
+        .await
+        .expect("Failed to get documents");
+
+    assert_eq!(
+        result
+            .documents
+            .iter()
+            .map(|b| b.title.as_str())
+            .collect::<Vec<_>>(),
+        ["Emma", "Dune"]
+    );
+    assert_eq!(result.missing, ["2"]);
+}
+
+#[tokio::test]
+async fn test_get_many_chunks_large_id_lists() {
+    let mock_server = MockServer::start().await;
+    let client = get_test_client(&mock_server.uri());
+
+    Mock::given(method("GET"))
+        .and(path("/collections/books/documents/export"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(""))
+        .expect(3)
+        .mount(&mock_server)
+        .await;
+
+    let ids = (0..250).map(|i| i.to_string()).collect::<Vec<_>>();
+    let result = client
+        .collection_named::<Book>("books")
+        .documents()
+        .get_many(&ids)
+        .await
+        .expect("Failed to get documents");
+
+    assert!(result.documents.is_empty());
+    assert_eq!(result.missing, ids);
+}
+
+#[tokio::test]
+async fn test_get_many_escapes_ids() {
+    let mock_server = MockServer::start().await;
+    let client = get_test_client(&mock_server.uri());
+
+    Mock::given(method("GET"))
+        .and(path("/collections/books/documents/export"))
+        .and(query_param("filter_by", r"id:=[`a\`b`,`c,d`]"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(""))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let result = client
+        .collection_named::<Book>("books")
+        .documents()
+        .get_many(["a`b", "c,d"])
+        .await
+        .expect("Failed to get documents");
+    assert_eq!(result.missing, ["a`b", "c,d"]);
+}
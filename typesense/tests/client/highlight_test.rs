@@ -0,0 +1,147 @@
+#![cfg(not(target_family = "wasm"))]
+
+use serde_json::json;
+use typesense::{
+    models::{FieldHighlight, HighlightRenderer, HighlightSegment, SearchResultHit},
+    prelude::*,
+};
+
+fn hit(value: serde_json::Value) -> SearchResultHit<serde_json::Value> {
+    serde_json::from_value(value).unwrap()
+}
+
+#[test]
+fn test_highlights_of_nested_and_array_fields() {
+    let hit = hit(json!({
+        "document": {},
+        "highlight": {
+            "title": {
+                "matched_tokens": ["Dune"],
+                "snippet": "<mark>Dune</mark> Messiah",
+                "value": "<mark>Dune</mark> Messiah"
+            },
+            "tags": [
+                { "matched_tokens": [], "snippet": "classic" },
+                { "matched_tokens": ["dune"], "snippet": "<mark>dune</mark>" }
+            ],
+            "author": {
+                "name": { "matched_tokens": ["Frank"], "snippet": "<mark>Frank</mark> Herbert" }
+            },
+            "editions": [
+                {
+                    "publishers": [
+                        { "matched_tokens": ["Ace"], "snippet": "<mark>Ace</mark> Books" }
+                    ]
+                }
+            ]
+        }
+    }));
+
+    let paths = hit
+        .highlights()
+        .into_iter()
+        .map(|highlight| (highlight.path, highlight.indices))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        paths,
+        [
+            ("author.name".to_owned(), vec![]),
+            ("editions.publishers".to_owned(), vec![0, 0]),
+            ("tags".to_owned(), vec![0]),
+            ("tags".to_owned(), vec![1]),
+            ("title".to_owned(), vec![]),
+        ]
+    );
+
+    assert_eq!(
+        hit.highlights_of("title"),
+        [FieldHighlight {
+            path: "title".to_owned(),
+            indices: vec![],
+            snippet: Some("<mark>Dune</mark> Messiah".to_owned()),
+            value: Some("<mark>Dune</mark> Messiah".to_owned()),
+            matched_tokens: vec!["Dune".to_owned()],
+        }]
+    );
+    assert_eq!(
+        hit.highlights_of("author.name")[0].matched_tokens,
+        ["Frank"]
+    );
+    assert!(hit.highlights_of("missing").is_empty());
+}
+
+#[test]
+fn test_highlights_fall_back_to_deprecated_list() {
+    let hit = hit(json!({
+        "document": {},
+        "highlights": [
+            {
+                "field": "title",
+                "snippet": "<mark>Dune</mark>",
+                "matched_tokens": ["Dune"]
+            },
+            {
+                "field": "tags",
+                "snippets": ["<mark>scifi</mark>", "<mark>sci</mark>-fi"],
+                "indices": [2, 5],
+                "matched_tokens": [["scifi"], ["sci"]]
+            }
+        ]
+    }));
+
+    let tags = hit.highlights_of("tags");
+    assert_eq!(tags.len(), 2);
+    assert_eq!(tags[1].indices, [5]);
+    assert_eq!(tags[1].snippet.as_deref(), Some("<mark>sci</mark>-fi"));
+    assert_eq!(tags[1].matched_tokens, ["sci"]);
+    assert_eq!(hit.highlights_of("title")[0].matched_tokens, ["Dune"]);
+}
+
+#[test]
+fn test_renderer_segments_and_html() {
+    let renderer = HighlightRenderer::default();
+    assert_eq!(
+        renderer.segments("a <mark>b</mark><mark>c</mark> d <mark>e"),
+        [
+            HighlightSegment {
+                text: "a ",
+                matched: false
+            },
+            HighlightSegment {
+                text: "b",
+                matched: true
+            },
+            HighlightSegment {
+                text: "c",
+                matched: true
+            },
+            HighlightSegment {
+                text: " d ",
+                matched: false
+            },
+            HighlightSegment {
+                text: "e",
+                matched: true
+            },
+        ]
+    );
+    assert_eq!(
+        renderer.to_html("<mark>Tom & Jerry</mark> \"<script>\""),
+        "<mark>Tom &amp; Jerry</mark> &quot;&lt;script&gt;&quot;"
+    );
+
+    let custom = HighlightRenderer::builder()
+        .highlight_start_tag("[[")
+        .highlight_end_tag("]]")
+        .start_tag("<b>")
+        .end_tag("</b>")
+        .build();
+    assert_eq!(custom.to_html("it's [[here]]"), "it&#39;s <b>here</b>");
+    assert_eq!(
+        custom.segments("no matches"),
+        [HighlightSegment {
+            text: "no matches",
+            matched: false
+        }]
+    );
+}
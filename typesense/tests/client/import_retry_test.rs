@@ -0,0 +1,260 @@
+#![cfg(not(target_family = "wasm"))]
+
+use super::get_test_client;
+use std::time::Duration;
+use typesense::{
+    ImportRetryError,
+    models::{
+        ImportDocumentsParameters, ImportLineErrorKind, ImportRetryOptions, classify_import_error,
+    },
+};
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{body_string, method, path},
+};
+
+#[test]
+fn test_classify_import_error() {
+    assert_eq!(
+        classify_import_error(Some(400), "Field `title` must be a string."),
+        ImportLineErrorKind::Permanent
+    );
+    assert_eq!(
+        classify_import_error(Some(409), "A document with id 1 already exists."),
+        ImportLineErrorKind::Permanent
+    );
+    assert_eq!(
+        classify_import_error(Some(503), "Not Ready or Lagging"),
+        ImportLineErrorKind::Retriable
+    );
+    assert_eq!(
+        classify_import_error(Some(502), "Remote embedding API request timed out."),
+        ImportLineErrorKind::Retriable
+    );
+    assert_eq!(
+        classify_import_error(None, "Remote embedding API request timed out."),
+        ImportLineErrorKind::Retriable
+    );
+    assert_eq!(
+        classify_import_error(None, "OpenAI API error: Rate limit reached"),
+        ImportLineErrorKind::Retriable
+    );
+}
+
+#[test]
+fn test_classify_import_error_ignores_field_names() {
+    let message =
+        "Field `connection_id` has been declared in the schema, but is not found in the document.";
+    assert_eq!(
+        classify_import_error(Some(400), message),
+        ImportLineErrorKind::Permanent
+    );
+    assert_eq!(
+        classify_import_error(None, message),
+        ImportLineErrorKind::Permanent
+    );
+    assert_eq!(
+        classify_import_error(None, "Field `timeout_unavailable` must be an int32."),
+        ImportLineErrorKind::Permanent
+    );
+    assert_eq!(
+        classify_import_error(Some(422), "Remote embedding API request timed out."),
+        ImportLineErrorKind::Permanent
+    );
+}
+
+#[tokio::test]
+async fn test_import_with_retry_and_dead_letters() {
+    let mock_server = MockServer::start().await;
+    let client = get_test_client(&mock_server.uri());
+
+    let doc_ok = r#"{"id":"1","title":"ok"}"#;
+    let doc_timeout = r#"{"id":"2","title":"timeout"}"#;
+    let doc_bad = r#"{"id":"3","title":3}"#;
+
+    // First request: one success, one transient failure, one permanent failure.
+    Mock::given(method("POST"))
+        .and(path("/collections/books/documents/import"))
+        .and(body_string(format!("{doc_ok}\n{doc_timeout}\n{doc_bad}")))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_string(
+                [
+                    r#"{"success":true}"#,
+                    r#"{"success":false,"code":503,"error":"Remote embedding request timed out."}"#,
+                    r#"{"success":false,"code":400,"error":"Field `title` must be a string."}"#,
+                ]
+                .join("\n"),
+            ),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    // Second request: only the transient failure is re-submitted, and succeeds.
+    Mock::given(method("POST"))
+        .and(path("/collections/books/documents/import"))
+        .and(body_string(doc_timeout))
+        .respond_with(ResponseTemplate::new(200).set_body_string(r#"{"success":true}"#))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let options = ImportRetryOptions::builder()
+        .initial_backoff(Duration::from_millis(1))
+        .build();
+    let mut dead_letters = Vec::new();
+    let report = client
+        .collection_schemaless("books")
+        .documents()
+        .import_jsonl_with_retry(
+            format!("{doc_ok}\n{doc_timeout}\n{doc_bad}\n"),
+            ImportDocumentsParameters::default(),
+            options,
+            &mut dead_letters,
+        )
+        .await
+        .expect("Import with retry failed");
+
+    assert_eq!(report.imported, 2);
+    assert_eq!(report.recovered, 1);
+    assert_eq!(report.dead_lettered, 1);
+    assert_eq!(report.requests, 2);
+
+    assert_eq!(dead_letters.len(), 1);
+    assert_eq!(dead_letters[0].document, doc_bad);
+    assert_eq!(dead_letters[0].code, Some(400));
+    assert_eq!(dead_letters[0].kind, ImportLineErrorKind::Permanent);
+    assert_eq!(dead_letters[0].attempts, 1);
+}
+
+#[tokio::test]
+async fn test_import_with_retry_exhausts_retries() {
+    let mock_server = MockServer::start().await;
+    let client = get_test_client(&mock_server.uri());
+
+    Mock::given(method("POST"))
+        .and(path("/collections/books/documents/import"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_string(r#"{"success":false,"code":503,"error":"Not Ready or Lagging"}"#),
+        )
+        .expect(3)
+        .mount(&mock_server)
+        .await;
+
+    let options = ImportRetryOptions::builder()
+        .max_retries(2)
+        .initial_backoff(Duration::from_millis(1))
+        .build();
+    let (mut sender, receiver) = std::sync::mpsc::channel();
+    let report = client
+        .collection_schemaless("books")
+        .documents()
+        .import_jsonl_with_retry(
+            r#"{"id":"1"}"#,
+            ImportDocumentsParameters::default(),
+            options,
+            &mut sender,
+        )
+        .await
+        .expect("Import with retry failed");
+
+    assert_eq!(report.imported, 0);
+    assert_eq!(report.dead_lettered, 1);
+    assert_eq!(report.requests, 3);
+
+    let failed = receiver.try_recv().expect("Expected a dead-lettered line");
+    assert_eq!(failed.kind, ImportLineErrorKind::Retriable);
+    assert_eq!(failed.attempts, 3);
+    assert_eq!(failed.error, "Not Ready or Lagging");
+}
+
+#[tokio::test]
+async fn test_import_with_retry_resends_transiently_failed_requests() {
+    let mock_server = MockServer::start().await;
+    let client = get_test_client(&mock_server.uri());
+
+    Mock::given(method("POST"))
+        .and(path("/collections/books/documents/import"))
+        .respond_with(ResponseTemplate::new(503).set_body_string("Not Ready or Lagging"))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/collections/books/documents/import"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(r#"{"success":true}"#))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let options = ImportRetryOptions::builder()
+        .initial_backoff(Duration::from_millis(1))
+        .build();
+    let report = client
+        .collection_schemaless("books")
+        .documents()
+        .import_jsonl_with_retry(
+            r#"{"id":"1"}"#,
+            ImportDocumentsParameters::default(),
+            options,
+            &mut Vec::new(),
+        )
+        .await
+        .expect("Import with retry failed");
+
+    assert_eq!(report.imported, 1);
+    assert_eq!(report.recovered, 1);
+    assert_eq!(report.requests, 2);
+}
+
+#[tokio::test]
+async fn test_import_with_retry_error_keeps_the_partial_report() {
+    let mock_server = MockServer::start().await;
+    let client = get_test_client(&mock_server.uri());
+
+    let doc_ok = r#"{"id":"1"}"#;
+    let doc_retry = r#"{"id":"2"}"#;
+    Mock::given(method("POST"))
+        .and(path("/collections/books/documents/import"))
+        .and(body_string(format!("{doc_ok}\n{doc_retry}")))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_string(
+                [
+                    r#"{"success":true}"#,
+                    r#"{"success":false,"code":503,"error":"Not Ready or Lagging"}"#,
+                ]
+                .join("\n"),
+            ),
+        )
+        .mount(&mock_server)
+        .await;
+    // The retry is rejected as a whole, which is not transient.
+    Mock::given(method("POST"))
+        .and(path("/collections/books/documents/import"))
+        .and(body_string(doc_retry))
+        .respond_with(ResponseTemplate::new(401).set_body_string("Forbidden"))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let options = ImportRetryOptions::builder()
+        .initial_backoff(Duration::from_millis(1))
+        .build();
+    let err = client
+        .collection_schemaless("books")
+        .documents()
+        .import_jsonl_with_retry(
+            format!("{doc_ok}\n{doc_retry}"),
+            ImportDocumentsParameters::default(),
+            options,
+            &mut Vec::new(),
+        )
+        .await
+        .expect_err("The retry should fail");
+
+    assert!(matches!(err, ImportRetryError::Import { .. }));
+    assert_eq!(err.report().imported, 1);
+    assert_eq!(err.report().requests, 2);
+    assert_eq!(err.unfinished(), [doc_retry]);
+}
@@ -0,0 +1,191 @@
+#![cfg(not(target_family = "wasm"))]
+
+use super::get_test_client;
+use std::time::Duration;
+use typesense::models::{
+    AdaptiveThrottle, ApiStatsResponse, ImportDocumentsParameters, ImportThrottleOptions,
+    ThrottleSignal,
+};
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{method, path},
+};
+
+fn stats(pending_write_batches: f64, write_latency_ms: f64) -> ApiStatsResponse {
+    ApiStatsResponse {
+        pending_write_batches: Some(pending_write_batches),
+        write_latency_ms: Some(write_latency_ms),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_adaptive_throttle_shrinks_and_grows() {
+    let options = ImportThrottleOptions::builder()
+        .initial_batch_size(400)
+        .min_batch_size(100)
+        .max_batch_size(600)
+        .initial_concurrency(4)
+        .max_concurrency(5)
+        .max_pending_write_batches(10.0)
+        .max_write_latency_ms(1000.0)
+        .build();
+    let mut throttle = AdaptiveThrottle::new(options);
+
+    // Backed up by pending write batches.
+    assert_eq!(
+        throttle.observe(&stats(20.0, 10.0)),
+        ThrottleSignal::Backpressure
+    );
+    assert_eq!((throttle.batch_size(), throttle.concurrency()), (200, 2));
+
+    // Backed up by write latency, down to the floor.
+    assert_eq!(
+        throttle.observe(&stats(0.0, 5000.0)),
+        ThrottleSignal::Backpressure
+    );
+    assert_eq!((throttle.batch_size(), throttle.concurrency()), (100, 1));
+    assert_eq!(
+        throttle.observe(&stats(0.0, 5000.0)),
+        ThrottleSignal::Backpressure
+    );
+    assert_eq!((throttle.batch_size(), throttle.concurrency()), (100, 1));
+
+    // Between half and full limits: nothing changes.
+    assert_eq!(throttle.observe(&stats(8.0, 10.0)), ThrottleSignal::Steady);
+    assert_eq!((throttle.batch_size(), throttle.concurrency()), (100, 1));
+
+    // Recovered: grows again, up to the maximums.
+    assert_eq!(
+        throttle.observe(&stats(1.0, 10.0)),
+        ThrottleSignal::Recovered
+    );
+    assert_eq!((throttle.batch_size(), throttle.concurrency()), (150, 2));
+    for _ in 0..10 {
+        throttle.observe(&stats(0.0, 0.0));
+    }
+    assert_eq!((throttle.batch_size(), throttle.concurrency()), (600, 5));
+    assert_eq!(throttle.observe(&stats(0.0, 0.0)), ThrottleSignal::Steady);
+}
+
+#[test]
+fn test_adaptive_throttle_grows_from_a_batch_size_of_one() {
+    let options = ImportThrottleOptions::builder()
+        .initial_batch_size(2)
+        .min_batch_size(1)
+        .max_batch_size(10)
+        .initial_concurrency(1)
+        .max_concurrency(1)
+        .build();
+    let mut throttle = AdaptiveThrottle::new(options);
+
+    let busy = stats(1000.0, 0.0);
+    assert_eq!(throttle.observe(&busy), ThrottleSignal::Backpressure);
+    assert_eq!(throttle.batch_size(), 1);
+
+    let sizes = (0..4)
+        .map(|_| {
+            assert_eq!(
+                throttle.observe(&stats(0.0, 0.0)),
+                ThrottleSignal::Recovered
+            );
+            throttle.batch_size()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(sizes, [2, 3, 4, 6]);
+}
+
+#[tokio::test]
+async fn test_import_throttled_backs_off_under_backpressure() {
+    let mock_server = MockServer::start().await;
+    let client = get_test_client(&mock_server.uri());
+
+    Mock::given(method("POST"))
+        .and(path("/collections/books/documents/import"))
+        .respond_with(|req: &wiremock::Request| {
+            let lines = String::from_utf8_lossy(&req.body).lines().count();
+            ResponseTemplate::new(200)
+                .set_body_string(vec![r#"{"success":true}"#; lines].join("\n"))
+        })
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/stats.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(stats(50.0, 10.0)))
+        .mount(&mock_server)
+        .await;
+
+    let documents = (0..10)
+        .map(|i| format!(r#"{{"id":"{i}"}}"#))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let options = ImportThrottleOptions::builder()
+        .initial_batch_size(4)
+        .min_batch_size(1)
+        .initial_concurrency(2)
+        .cooldown(Duration::from_millis(1))
+        .build();
+
+    let report = client
+        .collection_schemaless("books")
+        .documents()
+        .import_jsonl_throttled(documents, ImportDocumentsParameters::default(), options)
+        .await
+        .expect("Throttled import failed");
+
+    // Round 1: 2 batches of 4. Backpressure halves to 1 batch of 2, which finishes the import.
+    assert_eq!(report.batches, 3);
+    assert_eq!(report.throttled, 1);
+    assert_eq!(report.final_batch_size, 2);
+    assert_eq!(report.final_concurrency, 1);
+    assert_eq!(report.response.lines().count(), 10);
+    assert!(report.response.lines().all(|l| l == r#"{"success":true}"#));
+}
+
+#[tokio::test]
+async fn test_import_throttled_reports_partial_progress_on_failure() {
+    let mock_server = MockServer::start().await;
+    let client = get_test_client(&mock_server.uri());
+
+    // The batch with document 5 fails as a whole; the other batch of its round succeeds.
+    Mock::given(method("POST"))
+        .and(path("/collections/books/documents/import"))
+        .respond_with(|req: &wiremock::Request| {
+            let body = String::from_utf8_lossy(&req.body);
+            if body.contains(r#""id":"5""#) {
+                return ResponseTemplate::new(500).set_body_string("overloaded");
+            }
+            ResponseTemplate::new(200)
+                .set_body_string(vec![r#"{"success":true}"#; body.lines().count()].join("\n"))
+        })
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/stats.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(stats(0.0, 0.0)))
+        .mount(&mock_server)
+        .await;
+
+    let documents = (0..10)
+        .map(|i| format!(r#"{{"id":"{i}"}}"#))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let options = ImportThrottleOptions::builder()
+        .initial_batch_size(4)
+        .initial_concurrency(2)
+        .min_batch_size(1)
+        .build();
+
+    let error = client
+        .collection_schemaless("books")
+        .documents()
+        .import_jsonl_throttled(documents, ImportDocumentsParameters::default(), options)
+        .await
+        .expect_err("The failed batch should fail the import");
+
+    // Round 1 sent lines 0..8; no round was started after the failure.
+    assert_eq!(error.report.batches, 2);
+    assert_eq!(error.report.submitted_lines, 8);
+    assert_eq!(error.report.failed_batches, vec![(4..8)]);
+    assert_eq!(error.report.response.lines().count(), 4);
+}
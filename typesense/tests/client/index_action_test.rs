@@ -0,0 +1,116 @@
+#![cfg(not(target_family = "wasm"))]
+
+use super::get_test_client;
+use serde::{Deserialize, Serialize};
+use typesense::{
+    Typesense,
+    models::{DirtyValues, DocumentIndexParameters, IndexAction, UpdateDocumentsParameters},
+};
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{body_json, method, path, query_param},
+};
+
+#[derive(Typesense, Serialize, Deserialize, Debug, PartialEq)]
+#[typesense(collection_name = "books")]
+struct Book {
+    id: String,
+    title: String,
+    pages: i32,
+}
+
+#[tokio::test]
+async fn test_index_with_typed_action() {
+    let mock_server = MockServer::start().await;
+    let client = get_test_client(&mock_server.uri());
+    let partial = serde_json::json!({"id": "1", "pages": 500});
+
+    Mock::given(method("POST"))
+        .and(path("/collections/books/documents"))
+        .and(query_param("action", "update"))
+        .and(query_param("dirty_values", "coerce_or_reject"))
+        .and(body_json(&partial))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&partial))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let response = client
+        .collection::<Book>()
+        .documents()
+        .index(
+            &partial,
+            IndexAction::Update,
+            Some(DocumentIndexParameters {
+                dirty_values: Some(DirtyValues::CoerceOrReject),
+            }),
+        )
+        .await
+        .expect("Failed to index document");
+
+    assert_eq!(response, partial);
+}
+
+#[tokio::test]
+async fn test_emplace_document() {
+    let mock_server = MockServer::start().await;
+    let client = get_test_client(&mock_server.uri());
+    let book = Book {
+        id: "1".to_owned(),
+        title: "Dune".to_owned(),
+        pages: 412,
+    };
+
+    Mock::given(method("POST"))
+        .and(path("/collections/books/documents"))
+        .and(query_param("action", "emplace"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&book))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let emplaced = client
+        .collection::<Book>()
+        .documents()
+        .emplace(&book, None)
+        .await
+        .expect("Failed to emplace document");
+
+    assert_eq!(emplaced, book);
+}
+
+#[tokio::test]
+async fn test_update_by_filter_with_dirty_values() {
+    let mock_server = MockServer::start().await;
+    let client = get_test_client(&mock_server.uri());
+
+    Mock::given(method("PATCH"))
+        .and(path("/collections/books/documents"))
+        .and(query_param("filter_by", "pages:>400"))
+        .and(query_param("dirty_values", "coerce_or_drop"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({"num_updated": 2})),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let params = UpdateDocumentsParameters {
+        filter_by: Some("pages:>400".into()),
+        dirty_values: Some(DirtyValues::CoerceOrDrop),
+    };
+    let response = client
+        .collection::<Book>()
+        .documents()
+        .update(
+            &BookPartial {
+                title: Some("Long".to_owned()),
+                ..Default::default()
+            },
+            params,
+        )
+        .await
+        .expect("Failed to update documents");
+
+    assert_eq!(response.num_updated, 2);
+}
@@ -0,0 +1,102 @@
+#![cfg(not(target_family = "wasm"))]
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use typesense::{
+    Typesense,
+    models::{Joined, SearchResult},
+    prelude::*,
+};
+
+#[derive(Typesense, Serialize, Deserialize, Debug, PartialEq)]
+#[typesense(collection_name = "customers")]
+struct Customer {
+    id: String,
+    name: String,
+}
+
+#[derive(Typesense, Serialize, Deserialize, Debug, PartialEq)]
+#[typesense(collection_name = "orders")]
+struct Order {
+    total: f64,
+    #[typesense(reference = "customers.id")]
+    customer_id: String,
+}
+
+#[test]
+fn test_derive_records_referenced_collections() {
+    assert_eq!(Order::REFERENCED_COLLECTIONS, ["customers"]);
+    assert!(Customer::REFERENCED_COLLECTIONS.is_empty());
+    assert_eq!(Joined::<Order, Customer>::include_fields(), "$customers(*)");
+    assert_eq!(
+        Joined::<Customer, Vec<Order>>::include_fields(),
+        "$orders(*)"
+    );
+}
+
+#[test]
+fn test_search_result_with_joined_documents() {
+    let result: SearchResult<Joined<Order, Customer>> = serde_json::from_value(json!({
+        "found": 1,
+        "hits": [{
+            "document": {
+                "total": 25.5,
+                "customer_id": "c1",
+                "customers": { "id": "c1", "name": "Ada" }
+            }
+        }]
+    }))
+    .unwrap();
+
+    let order = result.hits.unwrap()[0].document.take().unwrap();
+    assert_eq!(
+        order,
+        Joined {
+            document: Order {
+                total: 25.5,
+                customer_id: "c1".to_owned(),
+            },
+            joined: Customer {
+                id: "c1".to_owned(),
+                name: "Ada".to_owned(),
+            },
+        }
+    );
+
+    // Serializing nests the joined document again.
+    assert_eq!(
+        serde_json::to_value(&order).unwrap(),
+        json!({
+            "total": 25.5,
+            "customer_id": "c1",
+            "customers": { "id": "c1", "name": "Ada" }
+        })
+    );
+}
+
+#[test]
+fn test_joined_lists_and_left_joins() {
+    // Orders reference customers, so a customer embeds the list of its orders.
+    let customer: Joined<Customer, Vec<Order>> = serde_json::from_value(json!({
+        "id": "c1",
+        "name": "Ada",
+        "orders": [
+            { "total": 10.0, "customer_id": "c1" },
+            { "total": 20.0, "customer_id": "c1" }
+        ]
+    }))
+    .unwrap();
+    assert_eq!(customer.document.name, "Ada");
+    assert_eq!(customer.joined.len(), 2);
+
+    let order: Joined<Order, Option<Customer>> =
+        serde_json::from_value(json!({ "total": 10.0, "customer_id": "c9" })).unwrap();
+    assert_eq!(order.joined, None);
+
+    let error = serde_json::from_value::<Joined<Order, Customer>>(json!({
+        "total": 10.0,
+        "customer_id": "c9"
+    }))
+    .unwrap_err();
+    assert!(error.to_string().starts_with("invalid joined `customers`"));
+}
@@ -1,19 +1,43 @@
 mod aliases_test;
 mod analytics_test;
 mod client_test;
+mod collection_clone_test;
 mod collections_test;
 mod conversation_models_test;
+mod csv_import_test;
 mod curation_sets_test;
 mod derive_integration_test;
+mod document_retrieve_test;
+mod document_validator_test;
 mod documents_test;
+mod ensure_test;
+mod facet_test;
+mod faceted_search_test;
+mod filter_test;
+mod geo_test;
+mod get_many_test;
+mod highlight_test;
 mod http_builder_test;
+mod import_retry_test;
+mod import_throttle_test;
+mod index_action_test;
+mod joined_test;
 mod keys_test;
 mod multi_search_test;
 mod operations_test;
 mod presets_test;
+mod reindex_test;
+mod schema_change_wait_test;
+mod schema_diff_test;
+mod scroll_test;
+mod search_pages_test;
+mod search_post_test;
+mod sort_test;
 mod stemming_dictionaries_test;
 mod stopwords_test;
 mod synonym_sets_test;
+mod typed_multi_search_test;
+mod vector_query_test;
 
 use std::time::Duration;
 use typesense::{Client, ExponentialBackoff};
@@ -30,6 +54,17 @@ pub fn get_client() -> Client {
         .expect("Failed to create Typesense client")
 }
 
+/// Creates a client for the mock server at `uri`, which doesn't retry failed requests.
+pub fn get_test_client(uri: &str) -> Client {
+    Client::builder()
+        .nodes(vec![uri])
+        .api_key("TEST_API_KEY")
+        .healthcheck_interval(Duration::from_secs(60))
+        .retry_policy(ExponentialBackoff::builder().build_with_max_retries(0))
+        .build()
+        .expect("Failed to create client")
+}
+
 /// Generates a unique name for a test resource by combining a prefix,
 /// a timestamp, and a nano id.
 /// e.g., "test_collection_123456789_aB1cD2eF"
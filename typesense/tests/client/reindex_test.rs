@@ -0,0 +1,175 @@
+#![cfg(not(target_family = "wasm"))]
+
+use super::get_test_client;
+use typesense::{
+    ReindexError,
+    models::{CollectionResponse, CollectionSchema, Field, ReindexOptions, ReindexSource},
+};
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{body_json, method, path},
+};
+
+fn collection(name: &str, num_documents: i64) -> CollectionResponse {
+    CollectionResponse {
+        name: name.to_owned(),
+        num_documents,
+        ..Default::default()
+    }
+}
+
+fn schema_v2() -> CollectionSchema<'static> {
+    CollectionSchema::builder()
+        .name("products_v2")
+        .fields(vec![
+            Field::builder().name("title").r#type("string").build(),
+        ])
+        .build()
+}
+
+/// Mounts the alias lookup, the collection creation and an import that answers every line with `line_response`.
+async fn mount_reindex_mocks(mock_server: &MockServer, line_response: &'static str) {
+    Mock::given(method("GET"))
+        .and(path("/aliases/products"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "name": "products",
+            "collection_name": "products_v1",
+        })))
+        .mount(mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/collections"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(collection("products_v2", 0)))
+        .mount(mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/collections/products_v1/documents/export"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_string(
+                [
+                    r#"{"id":"1","title":"Chair"}"#,
+                    r#"{"id":"2","title":"Table"}"#,
+                    r#"{"id":"3","title":"Lamp"}"#,
+                ]
+                .join("\n"),
+            ),
+        )
+        .mount(mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/collections/products_v2/documents/import"))
+        .respond_with(move |req: &wiremock::Request| {
+            let lines = String::from_utf8_lossy(&req.body).lines().count();
+            ResponseTemplate::new(200).set_body_string(vec![line_response; lines].join("\n"))
+        })
+        .mount(mock_server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_reindex_swaps_alias_and_drops_old_collection() {
+    let mock_server = MockServer::start().await;
+    let client = get_test_client(&mock_server.uri());
+    mount_reindex_mocks(&mock_server, r#"{"success":true}"#).await;
+
+    Mock::given(method("GET"))
+        .and(path("/collections/products_v2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(collection("products_v2", 3)))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path("/aliases/products"))
+        .and(body_json(
+            serde_json::json!({"collection_name": "products_v2"}),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "name": "products",
+            "collection_name": "products_v2",
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/collections/products_v1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(collection("products_v1", 3)))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let options = ReindexOptions::builder().batch_size(2).build();
+    let report = client
+        .alias("products")
+        .reindex(schema_v2(), ReindexSource::export(), options)
+        .await
+        .expect("Reindex failed");
+
+    assert_eq!(report.old_collection.as_deref(), Some("products_v1"));
+    assert_eq!(report.new_collection, "products_v2");
+    assert_eq!(report.documents, 3);
+    assert!(report.old_collection_deleted);
+
+    // The three exported documents are imported in batches of 2 and 1.
+    let imports = mock_server
+        .received_requests()
+        .await
+        .expect("Requests are recorded")
+        .into_iter()
+        .filter(|req| req.url.path() == "/collections/products_v2/documents/import")
+        .count();
+    assert_eq!(imports, 2);
+}
+
+#[tokio::test]
+async fn test_reindex_aborts_on_count_mismatch() {
+    let mock_server = MockServer::start().await;
+    let client = get_test_client(&mock_server.uri());
+    mount_reindex_mocks(
+        &mock_server,
+        r#"{"success":false,"code":400,"error":"Field `price` not found."}"#,
+    )
+    .await;
+
+    Mock::given(method("GET"))
+        .and(path("/collections/products_v2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(collection("products_v2", 0)))
+        .mount(&mock_server)
+        .await;
+    // The half-filled collection is dropped, and the alias is never touched.
+    Mock::given(method("DELETE"))
+        .and(path("/collections/products_v2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(collection("products_v2", 0)))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path("/aliases/products"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let err = client
+        .alias("products")
+        .reindex(
+            schema_v2(),
+            ReindexSource::Stream(futures::stream::iter([
+                r#"{"id":"1","title":"Chair"}"#.to_owned()
+            ])),
+            ReindexOptions::default(),
+        )
+        .await
+        .expect_err("Reindex should fail");
+
+    match err {
+        ReindexError::CountMismatch {
+            expected,
+            indexed,
+            failed,
+            first_error,
+        } => {
+            assert_eq!((expected, indexed, failed), (1, 0, 1));
+            assert_eq!(first_error.as_deref(), Some("Field `price` not found."));
+        }
+        other => panic!("Unexpected error: {other}"),
+    }
+}
@@ -0,0 +1,117 @@
+#![cfg(not(target_family = "wasm"))]
+
+use super::get_test_client;
+use std::{
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+use typesense::{
+    SchemaChangeWaitError,
+    models::{CollectionResponse, CollectionUpdateSchema, Field, SchemaChangeWaitOptions},
+};
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{method, path},
+};
+
+async fn mount_collection(mock_server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/collections/books"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(CollectionResponse {
+                name: "books".to_owned(),
+                ..Default::default()
+            }),
+        )
+        .mount(mock_server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_update_and_wait_reports_progress() {
+    let mock_server = MockServer::start().await;
+    let client = get_test_client(&mock_server.uri());
+    mount_collection(&mock_server).await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/collections/books"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({"fields": []})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    // The alter is reported as running on the first two polls, then finished.
+    let polls = AtomicUsize::new(0);
+    Mock::given(method("GET"))
+        .and(path("/operations/schema_changes"))
+        .respond_with(move |_: &wiremock::Request| {
+            let body = match polls.fetch_add(1, Ordering::SeqCst) {
+                n @ 0..2 => serde_json::json!([
+                    {"collection": "other", "validated_docs": 1, "altered_docs": 0},
+                    {"collection": "books", "validated_docs": (n + 1) * 50, "altered_docs": 0},
+                ]),
+                _ => serde_json::json!([]),
+            };
+            ResponseTemplate::new(200).set_body_json(body)
+        })
+        .expect(3)
+        .mount(&mock_server)
+        .await;
+
+    let update_schema = CollectionUpdateSchema {
+        fields: Some(vec![Field {
+            name: "year".to_owned(),
+            r#type: "int32".to_owned(),
+            ..Default::default()
+        }]),
+        ..Default::default()
+    };
+    let options = SchemaChangeWaitOptions::builder()
+        .poll_interval(Duration::from_millis(1))
+        .build();
+    let mut progress = Vec::new();
+    client
+        .collection_schemaless("books")
+        .update_and_wait(update_schema, options, |status| {
+            progress.push(status.validated_docs)
+        })
+        .await
+        .expect("Waiting for the alter failed");
+
+    assert_eq!(progress, [Some(50), Some(100)]);
+}
+
+#[tokio::test]
+async fn test_wait_for_schema_changes_times_out() {
+    let mock_server = MockServer::start().await;
+    let client = get_test_client(&mock_server.uri());
+    mount_collection(&mock_server).await;
+
+    Mock::given(method("GET"))
+        .and(path("/operations/schema_changes"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            {"collection": "books", "validated_docs": 10, "altered_docs": 0},
+        ])))
+        .mount(&mock_server)
+        .await;
+
+    let options = SchemaChangeWaitOptions::builder()
+        .timeout(Duration::from_millis(20))
+        .poll_interval(Duration::from_millis(5))
+        .build();
+    let err = client
+        .collection_schemaless("books")
+        .wait_for_schema_changes(options, |_| {})
+        .await
+        .expect_err("Waiting should time out");
+
+    match err {
+        SchemaChangeWaitError::Timeout {
+            elapsed,
+            last_status,
+        } => {
+            assert!(elapsed >= Duration::from_millis(20));
+            assert_eq!(last_status.validated_docs, Some(10));
+        }
+        other => panic!("Unexpected error: {other}"),
+    }
+}
@@ -0,0 +1,232 @@
+#![cfg(not(target_family = "wasm"))]
+
+use super::get_test_client;
+use serde::{Deserialize, Serialize};
+use typesense::{
+    Typesense,
+    models::{
+        CollectionResponse, CollectionSchema, Field, FieldAttribute, FieldEmbed,
+        FieldEmbedModelConfig, ReindexReason, SchemaDiff,
+    },
+};
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{method, path},
+};
+
+#[derive(Typesense, Serialize, Deserialize)]
+#[typesense(collection_name = "companies")]
+struct Company {
+    id: String,
+    company_name: String,
+    #[typesense(facet)]
+    country: String,
+    num_employees: i32,
+}
+
+/// A field as Typesense returns it, with every attribute filled in.
+fn live_field(name: &str, r#type: &str, facet: bool, sort: bool) -> Field {
+    Field {
+        name: name.to_owned(),
+        r#type: r#type.to_owned(),
+        facet: Some(facet),
+        sort: Some(sort),
+        index: Some(true),
+        optional: Some(false),
+        infix: Some(false),
+        locale: Some(String::new()),
+        stem: Some(false),
+        store: Some(true),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_schema_diff_between() {
+    let live = CollectionResponse {
+        name: "companies".to_owned(),
+        fields: vec![
+            live_field("company_name", "string", false, false),
+            live_field("num_employees", "int32", false, true),
+            live_field("country", "string", false, false),
+            live_field("legacy_code", "string", false, false),
+        ],
+        ..Default::default()
+    };
+    let desired = CollectionSchema::builder()
+        .name("companies")
+        .fields(vec![
+            Field::builder()
+                .name("company_name")
+                .r#type("string")
+                .build(),
+            Field::builder()
+                .name("num_employees")
+                .r#type("int32")
+                .build(),
+            Field::builder()
+                .name("country")
+                .r#type("string")
+                .facet(true)
+                .build(),
+            Field::builder().name("founded").r#type("int64").build(),
+        ])
+        .build();
+
+    let diff = SchemaDiff::between(&live, &desired);
+
+    assert_eq!(diff.added.len(), 1);
+    assert_eq!(diff.added[0].name, "founded");
+    assert_eq!(diff.removed.len(), 1);
+    assert_eq!(diff.removed[0].name, "legacy_code");
+    assert_eq!(diff.changed.len(), 1);
+    assert_eq!(diff.changed[0].desired.name, "country");
+    assert_eq!(diff.changed[0].changes.len(), 1);
+    assert_eq!(diff.changed[0].changes[0].attribute, FieldAttribute::Facet);
+    assert!(!diff.requires_reindex());
+
+    let update = diff.update_schema().expect("Expected an update schema");
+    let fields = update.fields.expect("Expected fields to update");
+    let summary = fields
+        .iter()
+        .map(|f| (f.name.as_str(), f.drop == Some(true)))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        summary,
+        [
+            ("legacy_code", true),
+            ("country", true),
+            ("country", false),
+            ("founded", false),
+        ]
+    );
+    assert_eq!(fields[2].facet, Some(true));
+}
+
+#[test]
+fn test_schema_diff_flags_reindex() {
+    let live = CollectionResponse {
+        name: "companies".to_owned(),
+        fields: vec![live_field("num_employees", "int32", false, true)],
+        default_sorting_field: Some(String::new()),
+        ..Default::default()
+    };
+    let desired = CollectionSchema::builder()
+        .name("companies")
+        .fields(vec![
+            Field::builder()
+                .name("num_employees")
+                .r#type("string")
+                .build(),
+        ])
+        .default_sorting_field("num_employees")
+        .build();
+
+    let diff = SchemaDiff::between(&live, &desired);
+
+    assert!(diff.requires_reindex());
+    assert_eq!(
+        diff.reindex_reasons,
+        [
+            ReindexReason::TypeChanged("num_employees".to_owned()),
+            ReindexReason::CollectionSetting("default_sorting_field"),
+        ]
+    );
+}
+
+#[test]
+fn test_schema_diff_flags_vector_changes() {
+    let embed = |model: &str| {
+        Box::new(FieldEmbed::new(
+            vec!["title".to_owned()],
+            FieldEmbedModelConfig::new(model.to_owned()),
+        ))
+    };
+    let live = CollectionResponse {
+        name: "books".to_owned(),
+        fields: vec![
+            Field {
+                num_dim: Some(384),
+                ..live_field("vector", "float[]", false, false)
+            },
+            Field {
+                embed: Some(embed("ts/all-MiniLM-L12-v2")),
+                ..live_field("embedding", "float[]", false, false)
+            },
+        ],
+        ..Default::default()
+    };
+    let desired = CollectionSchema::builder()
+        .name("books")
+        .fields(vec![
+            Field::builder()
+                .name("vector")
+                .r#type("float[]")
+                .num_dim(768)
+                .build(),
+            Field::builder()
+                .name("embedding")
+                .r#type("float[]")
+                .embed(embed("ts/e5-small"))
+                .build(),
+        ])
+        .build();
+
+    let diff = SchemaDiff::between(&live, &desired);
+
+    let attributes = diff
+        .changed
+        .iter()
+        .map(|change| {
+            let [change] = change.changes.as_slice() else {
+                panic!("Expected a single change, got {:?}", change.changes);
+            };
+            (change.attribute, change.desired.as_str())
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        attributes,
+        [
+            (FieldAttribute::NumDim, "768"),
+            (FieldAttribute::Embed, "ts/e5-small(title)"),
+        ]
+    );
+    assert!(diff.requires_reindex());
+    assert_eq!(
+        diff.reindex_reasons,
+        [
+            ReindexReason::VectorChanged("vector".to_owned()),
+            ReindexReason::VectorChanged("embedding".to_owned()),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_collection_schema_diff_in_sync() {
+    let mock_server = MockServer::start().await;
+    let client = get_test_client(&mock_server.uri());
+
+    let live = CollectionResponse {
+        name: "companies".to_owned(),
+        fields: vec![
+            live_field("company_name", "string", false, false),
+            live_field("country", "string", true, false),
+            live_field("num_employees", "int32", false, true),
+        ],
+        ..Default::default()
+    };
+    Mock::given(method("GET"))
+        .and(path("/collections/companies"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(live))
+        .mount(&mock_server)
+        .await;
+
+    let diff = client
+        .collection::<Company>()
+        .schema_diff()
+        .await
+        .expect("Failed to diff schema");
+
+    assert!(diff.is_empty(), "Unexpected diff: {diff:?}");
+    assert!(diff.update_schema().is_none());
+}
@@ -0,0 +1,144 @@
+#![cfg(not(target_family = "wasm"))]
+
+use super::get_test_client;
+use futures::TryStreamExt;
+use serde::Deserialize;
+use std::sync::{Arc, Mutex};
+use typesense::models::{ScrollOptions, SearchParameters};
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{method, path},
+};
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct Order {
+    id: String,
+    created_at: i64,
+}
+
+/// Answers searches over `orders` like Typesense would for the keyset filters sent by `scroll`,
+/// and records every `filter_by` it receives.
+fn keyset_server(orders: Vec<(&'static str, i64)>, filters: Arc<Mutex<Vec<String>>>) -> Mock {
+    Mock::given(method("GET"))
+        .and(path("/collections/orders/documents/search"))
+        .respond_with(move |req: &wiremock::Request| {
+            let query = req
+                .url
+                .query_pairs()
+                .into_owned()
+                .collect::<std::collections::HashMap<_, _>>();
+            assert_eq!(query["sort_by"], "created_at:asc,id:asc");
+            assert_eq!(query["page"], "1");
+            let per_page = query["per_page"].parse::<usize>().unwrap();
+            let filter_by = query.get("filter_by").cloned().unwrap_or_default();
+            filters.lock().unwrap().push(filter_by.clone());
+
+            // `(status:=shipped) && (created_at:>V || (created_at:=V && id:>`ID`))`
+            let after = filter_by
+                .split_once("created_at:>")
+                .map(|(_, rest)| rest.split(' ').next().unwrap().parse::<i64>().unwrap());
+            let after_id = filter_by
+                .split_once("id:>`")
+                .map(|(_, rest)| rest.split('`').next().unwrap().to_owned());
+            let hits = orders
+                .iter()
+                .filter(|(id, ts)| match (after, &after_id) {
+                    (Some(after), Some(after_id)) => {
+                        *ts > after || (*ts == after && *id > after_id.as_str())
+                    }
+                    _ => true,
+                })
+                .take(per_page)
+                .map(|(id, ts)| serde_json::json!({ "document": { "id": id, "created_at": ts } }))
+                .collect::<Vec<_>>();
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "found": orders.len(),
+                "hits": hits,
+            }))
+        })
+}
+
+#[tokio::test]
+async fn test_scroll_walks_all_hits_across_ties() {
+    let mock_server = MockServer::start().await;
+    let client = get_test_client(&mock_server.uri());
+
+    // Sorted by `created_at`, with ties that span page boundaries.
+    let orders = vec![
+        ("a", 10),
+        ("b", 20),
+        ("c", 20),
+        ("d", 20),
+        ("e", 20),
+        ("f", 30),
+        ("g", 40),
+    ];
+    let filters = Arc::new(Mutex::new(Vec::new()));
+    keyset_server(orders, filters.clone())
+        .mount(&mock_server)
+        .await;
+
+    let collection = client.collection_named::<Order>("orders");
+    let documents = collection.documents();
+    let params = SearchParameters::builder()
+        .q("*")
+        .filter_by("status:=shipped")
+        .page(7)
+        .build();
+    let options = ScrollOptions::builder()
+        .sort_field("created_at")
+        .per_page(2)
+        .build();
+    let hits = documents
+        .scroll(params, options)
+        .try_collect::<Vec<_>>()
+        .await
+        .expect("Scroll failed");
+
+    let ids = hits
+        .iter()
+        .map(|hit| hit.document.as_ref().unwrap().id.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(ids, ["a", "b", "c", "d", "e", "f", "g"]);
+
+    let filters = filters.lock().unwrap();
+    assert_eq!(
+        *filters,
+        [
+            "status:=shipped",
+            "(status:=shipped) && (created_at:>20 || (created_at:=20 && id:>`b`))",
+            "(status:=shipped) && (created_at:>20 || (created_at:=20 && id:>`d`))",
+            "(status:=shipped) && (created_at:>30 || (created_at:=30 && id:>`f`))",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_scroll_stops_on_an_empty_page() {
+    let mock_server = MockServer::start().await;
+    let client = get_test_client(&mock_server.uri());
+
+    let filters = Arc::new(Mutex::new(Vec::new()));
+    keyset_server(vec![("a", 1), ("b", 2)], filters.clone())
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    let options = ScrollOptions::builder()
+        .sort_field("created_at")
+        .per_page(2)
+        .build();
+    let hits = client
+        .collection_named::<Order>("orders")
+        .documents()
+        .scroll(SearchParameters::builder().q("*").build(), options)
+        .try_collect::<Vec<_>>()
+        .await
+        .expect("Scroll failed");
+
+    assert_eq!(hits.len(), 2);
+    assert_eq!(
+        filters.lock().unwrap()[1],
+        "created_at:>2 || (created_at:=2 && id:>`b`)"
+    );
+}
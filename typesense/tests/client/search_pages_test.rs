@@ -0,0 +1,142 @@
+#![cfg(not(target_family = "wasm"))]
+
+use super::get_test_client;
+use futures::TryStreamExt;
+use std::time::Duration;
+use typesense::models::{SearchPagesOptions, SearchParameters};
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{method, path},
+};
+
+/// Serves `found` books in pages of the requested size, each after `delay`.
+async fn mount_books(mock_server: &MockServer, found: usize, delay: Duration) {
+    Mock::given(method("GET"))
+        .and(path("/collections/books/documents/search"))
+        .respond_with(move |req: &wiremock::Request| {
+            let param = |name: &str| {
+                req.url
+                    .query_pairs()
+                    .find(|(key, _)| key == name)
+                    .map(|(_, value)| value.parse::<usize>().unwrap())
+            };
+            let (page, per_page) = (param("page").unwrap(), param("per_page").unwrap_or(10));
+            let hits = ((page - 1) * per_page..found.min(page * per_page))
+                .map(|i| serde_json::json!({ "document": { "id": i.to_string() } }))
+                .collect::<Vec<_>>();
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!({
+                    "found": found,
+                    "page": page,
+                    "hits": hits,
+                }))
+                .set_delay(delay)
+        })
+        .mount(mock_server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_search_pages_stops_at_found() {
+    let mock_server = MockServer::start().await;
+    let client = get_test_client(&mock_server.uri());
+    mount_books(&mock_server, 25, Duration::ZERO).await;
+
+    let books = client.collection_schemaless("books");
+    let documents = books.documents();
+    let params = SearchParameters::builder().q("*").page(2).build();
+    let pages = documents
+        .search_pages(params, SearchPagesOptions::default())
+        .try_collect::<Vec<_>>()
+        .await
+        .expect("Search failed");
+
+    assert_eq!(
+        pages.iter().map(|p| p.page.unwrap()).collect::<Vec<_>>(),
+        [2, 3]
+    );
+    assert_eq!(pages[1].hits.as_ref().unwrap().len(), 5);
+    assert_eq!(mock_server.received_requests().await.unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_search_pages_without_hits_yields_one_page() {
+    let mock_server = MockServer::start().await;
+    let client = get_test_client(&mock_server.uri());
+    mount_books(&mock_server, 0, Duration::ZERO).await;
+
+    let books = client.collection_schemaless("books");
+    let documents = books.documents();
+    let pages = documents
+        .search_pages(
+            SearchParameters::builder().q("*").build(),
+            SearchPagesOptions::default(),
+        )
+        .try_collect::<Vec<_>>()
+        .await
+        .expect("Search failed");
+
+    assert_eq!(pages.len(), 1);
+    assert_eq!(pages[0].found, Some(0));
+}
+
+#[tokio::test]
+async fn test_search_pages_prefetches_the_next_page() {
+    for (prefetch, requests) in [(false, 2), (true, 3)] {
+        let mock_server = MockServer::start().await;
+        let client = get_test_client(&mock_server.uri());
+        // While the stream waits for a page, a prefetched request has time to reach the server.
+        mount_books(&mock_server, 40, Duration::from_millis(200)).await;
+
+        let books = client.collection_schemaless("books");
+        let documents = books.documents();
+        let params = SearchParameters::builder().q("*").per_page(10).build();
+        let options = SearchPagesOptions::builder().prefetch(prefetch).build();
+        let pages = documents.search_pages(params, options);
+        futures::pin_mut!(pages);
+
+        // The first page is fetched alone, as the number of pages is not known before it.
+        let first = pages.try_next().await.expect("Search failed").unwrap();
+        assert_eq!(first.page, Some(1));
+        assert_eq!(mock_server.received_requests().await.unwrap().len(), 1);
+
+        // The third page is requested before the second one is handed out.
+        let second = pages.try_next().await.expect("Search failed").unwrap();
+        assert_eq!(second.page, Some(2));
+        assert_eq!(
+            mock_server.received_requests().await.unwrap().len(),
+            requests,
+            "prefetch: {prefetch}"
+        );
+
+        let remaining = pages.try_collect::<Vec<_>>().await.expect("Search failed");
+        assert_eq!(
+            remaining
+                .iter()
+                .map(|p| p.page.unwrap())
+                .collect::<Vec<_>>(),
+            [3, 4]
+        );
+        assert_eq!(mock_server.received_requests().await.unwrap().len(), 4);
+    }
+}
+
+#[tokio::test]
+async fn test_search_pages_does_not_prefetch_past_the_last_page() {
+    let mock_server = MockServer::start().await;
+    let client = get_test_client(&mock_server.uri());
+    mount_books(&mock_server, 5, Duration::ZERO).await;
+
+    let books = client.collection_schemaless("books");
+    let documents = books.documents();
+    let options = SearchPagesOptions::builder().prefetch(true).build();
+    let pages = documents
+        .search_pages(SearchParameters::builder().q("*").build(), options)
+        .try_collect::<Vec<_>>()
+        .await
+        .expect("Search failed");
+
+    assert_eq!(pages.len(), 1);
+    assert_eq!(pages[0].page, Some(1));
+    assert_eq!(mock_server.received_requests().await.unwrap().len(), 1);
+}
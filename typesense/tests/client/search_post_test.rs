@@ -0,0 +1,188 @@
+#![cfg(not(target_family = "wasm"))]
+
+use serde::Deserialize;
+use std::time::Duration;
+use typesense::{Client, ExponentialBackoff, models::SearchParameters};
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{body_partial_json, method, path, query_param},
+};
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct Book {
+    id: String,
+    title: String,
+}
+
+fn get_test_client(uri: &str) -> Client {
+    Client::builder()
+        .nodes(vec![uri])
+        .api_key("TEST_API_KEY")
+        .healthcheck_interval(Duration::from_secs(60))
+        .retry_policy(ExponentialBackoff::builder().build_with_max_retries(0))
+        .max_search_query_length(200)
+        .build()
+        .expect("Failed to create client")
+}
+
+fn long_id_filter() -> String {
+    let ids = (0..100).map(|i| i.to_string()).collect::<Vec<_>>();
+    format!("id:[{}]", ids.join(","))
+}
+
+#[tokio::test]
+async fn test_short_search_uses_get() {
+    let mock_server = MockServer::start().await;
+    let client = get_test_client(&mock_server.uri());
+
+    Mock::given(method("GET"))
+        .and(path("/collections/books/documents/search"))
+        .and(query_param("q", "dune"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "found": 0,
+            "hits": [],
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let result = client
+        .collection_named::<Book>("books")
+        .documents()
+        .search(
+            SearchParameters::builder()
+                .q("dune")
+                .query_by("title")
+                .build(),
+        )
+        .await
+        .expect("Search failed");
+    assert_eq!(result.found, Some(0));
+}
+
+#[tokio::test]
+async fn test_long_search_is_sent_as_multi_search() {
+    let mock_server = MockServer::start().await;
+    let client = get_test_client(&mock_server.uri());
+
+    Mock::given(method("POST"))
+        .and(path("/multi_search"))
+        .and(body_partial_json(serde_json::json!({
+            "searches": [{
+                "collection": "books",
+                "q": "*",
+                "filter_by": long_id_filter(),
+                "split_join_tokens": "off",
+            }],
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [{
+                "found": 1,
+                "hits": [{ "document": { "id": "7", "title": "Dune" } }],
+            }],
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let params = SearchParameters::builder()
+        .q("*")
+        .filter_by(long_id_filter())
+        .split_join_tokens("off")
+        .build();
+    let result = client
+        .collection_named::<Book>("books")
+        .documents()
+        .search(params)
+        .await
+        .expect("Search failed");
+
+    assert_eq!(result.found, Some(1));
+    assert_eq!(
+        result.hits.unwrap()[0].document,
+        Some(Book {
+            id: "7".to_owned(),
+            title: "Dune".to_owned(),
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_failed_multi_search_keeps_the_status() {
+    let mock_server = MockServer::start().await;
+    let client = get_test_client(&mock_server.uri());
+
+    Mock::given(method("POST"))
+        .and(path("/multi_search"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [{ "code": 404, "error": "Not found." }],
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let err = client
+        .collection_named::<Book>("books")
+        .documents()
+        .search(
+            SearchParameters::builder()
+                .q("*")
+                .filter_by(long_id_filter())
+                .build(),
+        )
+        .await
+        .unwrap_err();
+
+    match err {
+        typesense::Error::Api(typesense::ApiError::ResponseError(response)) => {
+            assert_eq!(response.status, 404);
+            assert!(matches!(
+                response.entity,
+                Some(typesense::legacy::apis::documents_api::SearchCollectionError::Status404(ref r))
+                    if r.message == "Not found."
+            ));
+        }
+        other => panic!("Unexpected error: {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_query_length_counts_the_encoded_parameters() {
+    let mock_server = MockServer::start().await;
+    let client = get_test_client(&mock_server.uri());
+
+    Mock::given(method("GET"))
+        .and(path("/collections/books/documents/search"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "found": 0,
+            "hits": [],
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/multi_search"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [{ "found": 0, "hits": [] }],
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    // Both filters are far below the limit of 200 as written, but every `:`, `[`, `,` and `]`
+    // takes three characters once encoded: 197 characters are sent with GET, 202 with POST.
+    for ids in [37, 38] {
+        let ids = (0..ids).map(|i| i.to_string()).collect::<Vec<_>>();
+        client
+            .collection_named::<Book>("books")
+            .documents()
+            .search(
+                SearchParameters::builder()
+                    .q("*")
+                    .filter_by(format!("id:[{}]", ids.join(",")))
+                    .build(),
+            )
+            .await
+            .expect("Search failed");
+    }
+}
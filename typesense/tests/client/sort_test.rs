@@ -0,0 +1,124 @@
+#![cfg(not(target_family = "wasm"))]
+
+use std::borrow::Cow;
+use typesense::{
+    models::{
+        Filter, GeoDistance, GeoPoint, SearchParameters, SortBy, SortField, SortOrder,
+        SortableByDefault, is_sortable_by_default,
+    },
+    prelude::ToTypesenseField,
+};
+
+#[test]
+fn test_sort_fields() {
+    let cases = [
+        (SortField::asc("price"), "price:asc"),
+        (SortField::desc("popularity"), "popularity:desc"),
+        (
+            SortField::text_match(SortOrder::Desc).into(),
+            "_text_match:desc",
+        ),
+        (
+            SortField::text_match(SortOrder::Desc).buckets(10).into(),
+            "_text_match(buckets: 10):desc",
+        ),
+        (
+            SortField::text_match(SortOrder::Asc).bucket_size(3).into(),
+            "_text_match(bucket_size: 3):asc",
+        ),
+        (
+            SortField::eval(Filter::field("in_stock").eq(true), SortOrder::Desc),
+            "_eval(in_stock:=true):desc",
+        ),
+        (
+            SortField::eval_weighted(
+                [
+                    (Filter::field("brand").eq("Acme"), 3),
+                    (
+                        Filter::field("tags")
+                            .matches("sale")
+                            .and(Filter::field("price").lt(10)),
+                        2,
+                    ),
+                ],
+                SortOrder::Desc,
+            ),
+            "_eval([(brand:=`Acme`):3, (tags:`sale` && price:<10):2]):desc",
+        ),
+        (
+            SortField::geo_distance("location", GeoPoint::new(48.85, 2.29), SortOrder::Asc).into(),
+            "location(48.85, 2.29):asc",
+        ),
+        (
+            SortField::geo_distance("location", GeoPoint::new(48.85, 2.29), SortOrder::Asc)
+                .exclude_radius(GeoDistance::Miles(2.0))
+                .precision(GeoDistance::Km(0.5))
+                .into(),
+            "location(48.85, 2.29, exclude_radius: 2mi, precision: 0.5km):asc",
+        ),
+        (
+            SortField::vector_distance(SortOrder::Asc),
+            "_vector_distance:asc",
+        ),
+        (SortField::group_found(SortOrder::Desc), "_group_found:desc"),
+    ];
+    for (field, expected) in cases {
+        assert_eq!(field.to_string(), expected);
+    }
+}
+
+#[test]
+fn test_sort_by_joins_up_to_three_fields() {
+    let one = SortBy::by(SortField::desc("rating"));
+    assert_eq!(one.to_string(), "rating:desc");
+
+    let two = one.clone().then(SortField::text_match(SortOrder::Desc));
+    assert_eq!(two.to_string(), "rating:desc,_text_match:desc");
+
+    let three = two.then(SortField::asc("price"));
+    assert_eq!(three.to_string(), "rating:desc,_text_match:desc,price:asc");
+}
+
+#[test]
+fn test_sort_by_plugs_into_search_parameters() {
+    let sort_by = SortBy::by(SortField::group_found(SortOrder::Desc)).then(SortField::asc("price"));
+    let expected = "_group_found:desc,price:asc";
+
+    let params = SearchParameters::builder()
+        .q("*")
+        .sort_by(sort_by.clone())
+        .build();
+    assert_eq!(params.sort_by.as_deref(), Some(expected));
+
+    let sort_by: Cow<'_, str> = sort_by.clone().into();
+    assert_eq!(sort_by, expected);
+}
+
+#[test]
+fn test_sortable_by_default() {
+    for r#type in ["int32", "int64", "float", "bool", "geopoint"] {
+        assert!(is_sortable_by_default(r#type), "{type} should be sortable");
+    }
+    for r#type in [
+        "string",
+        "int32[]",
+        "geopoint[]",
+        "geopolygon",
+        "object",
+        "auto",
+    ] {
+        assert!(
+            !is_sortable_by_default(r#type),
+            "{type} should not be sortable"
+        );
+    }
+
+    // The Rust types that are sortable by default map to exactly those types.
+    fn sortable<T: SortableByDefault + ToTypesenseField>() -> bool {
+        is_sortable_by_default(T::to_typesense_type())
+    }
+    assert!(sortable::<i8>() && sortable::<u8>() && sortable::<i16>() && sortable::<u16>());
+    assert!(sortable::<i32>() && sortable::<u32>() && sortable::<i64>() && sortable::<u64>());
+    assert!(sortable::<isize>() && sortable::<usize>() && sortable::<f32>() && sortable::<f64>());
+    assert!(sortable::<bool>() && sortable::<GeoPoint>());
+}
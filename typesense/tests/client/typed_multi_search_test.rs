@@ -0,0 +1,129 @@
+#![cfg(not(target_family = "wasm"))]
+
+use super::get_test_client;
+use serde::Deserialize;
+use serde_json::json;
+use typesense::{
+    MultiSearchParseError,
+    models::{MultiSearchCollectionParameters, MultiSearchParameters},
+};
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{body_partial_json, method, path},
+};
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct Product {
+    id: String,
+    name: String,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct Brand {
+    id: String,
+    company_name: String,
+}
+
+#[tokio::test]
+async fn test_typed_multi_search_returns_a_result_per_search() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/multi_search"))
+        .and(body_partial_json(json!({
+            "searches": [
+                { "collection": "products", "q": "shoe" },
+                { "collection": "brands", "q": "nike" },
+                { "collection": "products", "q": "sock" }
+            ]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [
+                {
+                    "found": 1,
+                    "hits": [{ "document": { "id": "1", "name": "Running shoe" } }]
+                },
+                {
+                    "found": 1,
+                    "hits": [{ "document": { "id": "2", "company_name": "Nike" } }]
+                },
+                { "code": 404, "error": "Not found." }
+            ]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = get_test_client(&server.uri());
+    let products = client.collection_named::<Product>("products");
+    let brands = client.collection_named::<Brand>("brands");
+    let search = |q: &'static str| MultiSearchCollectionParameters::builder().q(q).build();
+
+    let (shoes, nike, socks) = client
+        .multi_search()
+        .search(&products, search("shoe"))
+        .search(&brands, search("nike"))
+        .search(&products, search("sock"))
+        .perform(MultiSearchParameters::default())
+        .await
+        .unwrap();
+
+    let shoes = shoes.unwrap().hits.unwrap();
+    assert_eq!(
+        shoes[0].document,
+        Some(Product {
+            id: "1".to_owned(),
+            name: "Running shoe".to_owned(),
+        })
+    );
+    let nike = nike.unwrap().hits.unwrap();
+    assert_eq!(
+        nike[0].document,
+        Some(Brand {
+            id: "2".to_owned(),
+            company_name: "Nike".to_owned(),
+        })
+    );
+    match socks {
+        Err(MultiSearchParseError::ApiError { index, message }) => {
+            assert_eq!(index, 2);
+            assert_eq!(message, "Not found.");
+        }
+        other => panic!("expected an API error, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_typed_multi_search_reports_deserialization_errors_per_search() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/multi_search"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [
+                { "found": 1, "hits": [{ "document": { "id": "1" } }] },
+                { "found": 0, "hits": [] }
+            ]
+        })))
+        .mount(&server)
+        .await;
+
+    let client = get_test_client(&server.uri());
+    let (products, brands) = client
+        .multi_search()
+        .search(
+            &client.collection_named::<Product>("products"),
+            MultiSearchCollectionParameters::default(),
+        )
+        .search(
+            &client.collection_named::<Brand>("brands"),
+            MultiSearchCollectionParameters::default(),
+        )
+        .perform(MultiSearchParameters::default())
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        products,
+        Err(MultiSearchParseError::Deserialization { index: 0, .. })
+    ));
+    assert_eq!(brands.unwrap().found, Some(0));
+}
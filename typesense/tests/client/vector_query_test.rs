@@ -0,0 +1,83 @@
+#![cfg(not(target_family = "wasm"))]
+
+use serde_json::json;
+use typesense::{
+    VectorQueryError,
+    models::{SearchParameters, SearchResultHit, VectorQuery},
+    prelude::*,
+};
+
+#[test]
+fn test_vector_query_sources() {
+    assert_eq!(
+        VectorQuery::vector("embedding", &[0.5, -1.0, 0.125]).to_string(),
+        "embedding:([0.5,-1,0.125])"
+    );
+    assert_eq!(
+        VectorQuery::similar_to("embedding", "doc-42")
+            .unwrap()
+            .k(10)
+            .to_string(),
+        "embedding:([], id: doc-42, k:10)"
+    );
+    for id in ["a, k:1", "a)", "(a", "`a`"] {
+        assert_eq!(
+            VectorQuery::similar_to("embedding", id),
+            Err(VectorQueryError::InvalidId(id.to_owned()))
+        );
+    }
+    assert_eq!(
+        VectorQuery::embed_query("embedding").to_string(),
+        "embedding:([])"
+    );
+}
+
+#[test]
+fn test_vector_query_options() {
+    let vector_query = VectorQuery::vector("embedding", &[0.1, 0.2])
+        .k(100)
+        .alpha(0.3)
+        .distance_threshold(0.5)
+        .ef(64)
+        .flat_search_cutoff(20);
+    assert_eq!(
+        vector_query.to_string(),
+        "embedding:([0.1,0.2], k:100, alpha:0.3, distance_threshold:0.5, ef:64, \
+         flat_search_cutoff:20)"
+    );
+
+    let params = SearchParameters::builder()
+        .q("*")
+        .vector_query(vector_query.clone())
+        .build();
+    assert_eq!(
+        params.vector_query.as_deref(),
+        Some(vector_query.to_string().as_str())
+    );
+}
+
+#[test]
+fn test_hit_scores() {
+    let hit: SearchResultHit<serde_json::Value> = serde_json::from_value(json!({
+        "document": { "id": "1" },
+        "text_match": 1060320051,
+        "vector_distance": 0.25,
+        "hybrid_search_info": { "rank_fusion_score": 0.75 }
+    }))
+    .unwrap();
+    assert_eq!(SearchResultHitExt::vector_distance(&hit), Some(0.25));
+    assert_eq!(hit.vector_similarity(), Some(0.75));
+    assert_eq!(
+        hit.hybrid_scores(),
+        Some(HybridSearchScores {
+            rank_fusion_score: 0.75,
+            text_match: Some(1060320051),
+            vector_distance: Some(0.25),
+        })
+    );
+
+    let keyword_hit: SearchResultHit<serde_json::Value> =
+        serde_json::from_value(json!({ "document": { "id": "2" }, "text_match": 100 })).unwrap();
+    assert_eq!(SearchResultHitExt::vector_distance(&keyword_hit), None);
+    assert_eq!(keyword_hit.hybrid_scores(), None);
+}
@@ -383,3 +383,111 @@ fn derived_document_handles_boolean_shorthand() {
 
     assert_eq!(serde_json::to_value(schema).unwrap(), expected);
 }
+
+// Test 6: Typed Field Accessors
+
+#[test]
+fn derived_document_generates_field_accessors() {
+    use typesense::models::{FacetBy, Filter, QueryBy, SortBy, SortField};
+
+    // Renames and raw identifiers use the schema name.
+    let fields = KitchenSinkProduct::fields();
+    assert_eq!(fields.name.name(), "product_name");
+    assert_eq!(fields.price.name(), "renamed_price");
+    assert_eq!(RawIdentDoc::fields().r#type.name(), "type");
+
+    // Flattened fields are nested and prefixed, with the rename as prefix.
+    let fields = User::fields();
+    assert_eq!(fields.profile.name.name(), "profile.name");
+    assert_eq!(fields.sub_fields_only.email.name(), "sub_fields_only.email");
+    assert_eq!(
+        fields.nested_struct.address.primary_city.name(),
+        "nested_struct.address.primary_city"
+    );
+    assert_eq!(fields.primary_city.name(), "primary_address.city");
+    assert_eq!(RawIdentFlat::fields().r#match.value.name(), "match.value");
+
+    // Facet, sort and index flags decide which builders take a field.
+    let fields = Company::fields();
+    let facet_by = FacetBy::new().field(&fields.country);
+    let sort_by = SortBy::by(SortField::desc(fields.num_employees))
+        .then(SortField::asc(User::fields().profile.name));
+    let query_by = QueryBy::new()
+        .field(fields.company_name)
+        .field(&fields.country);
+    let filter = Filter::field(fields.country).eq("NZ");
+
+    assert_eq!(facet_by.to_string(), "country");
+    assert_eq!(sort_by.to_string(), "num_employees:desc,profile.name:asc");
+    assert_eq!(query_by.to_string(), "company_name,country");
+    assert_eq!(filter.to_string(), "country:=`NZ`");
+}
+
+// Test 7: Geo Types
+
+#[allow(dead_code)]
+#[derive(Typesense, Serialize, Deserialize)]
+#[typesense(collection_name = "stores")]
+struct Store {
+    name: String,
+    location: typesense::models::GeoPoint,
+    entrances: Vec<typesense::models::GeoPoint>,
+    delivery_area: Option<typesense::models::GeoPolygon>,
+}
+
+#[test]
+fn derived_document_maps_geo_types() {
+    use typesense::models::{Filter, GeoDistance, GeoPoint, SortField, SortOrder};
+
+    let schema = Store::collection_schema();
+    let expected = json!({
+      "name": "stores",
+      "fields": [
+        { "name": "name", "type": "string" },
+        { "name": "location", "type": "geopoint" },
+        { "name": "entrances", "type": "geopoint[]" },
+        { "name": "delivery_area", "type": "geopolygon", "optional": true },
+      ]
+    });
+    assert_eq!(serde_json::to_value(&schema).unwrap(), expected);
+
+    // Geopoints are sortable by default.
+    let fields = Store::fields();
+    let paris = GeoPoint::new(48.85, 2.29);
+    let sort = SortField::asc(&fields.location);
+    let geo_sort = SortField::from(SortField::geo_distance(
+        &fields.location,
+        paris,
+        SortOrder::Asc,
+    ));
+    let filter = Filter::field(fields.entrances).within_radius(paris, GeoDistance::Km(1.0));
+
+    assert_eq!(sort.to_string(), "location:asc");
+    assert_eq!(geo_sort.to_string(), "location(48.85, 2.29):asc");
+    assert_eq!(filter.to_string(), "entrances:(48.85, 2.29, 1 km)");
+}
+
+#[allow(dead_code)]
+#[derive(Typesense, Serialize, Deserialize)]
+#[typesense(collection_name = "listings")]
+struct Listing {
+    #[typesense(type = "geopoint")]
+    location: [f64; 2],
+    #[typesense(type = "bool")]
+    available: u8,
+}
+
+#[test]
+fn derived_document_sorts_declared_types_by_default() {
+    use typesense::models::{SortBy, SortField};
+
+    // Declared types follow the same rule as the Rust types: geopoints and booleans are sortable.
+    let fields = Listing::fields();
+    let sort_by =
+        SortBy::by(SortField::asc(fields.location)).then(SortField::desc(fields.available));
+    assert_eq!(sort_by.to_string(), "location:asc,available:desc");
+    assert_eq!(
+        SortField::asc(KitchenSinkProduct::fields().location).to_string(),
+        "location:asc"
+    );
+}
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+use typesense::{Typesense, models::FacetBy, prelude::*};
+#[derive(Typesense, Serialize, Deserialize)]
+struct Company {
+    company_name: String,
+    #[typesense(facet)]
+    country: String,
+}
+
+fn main() {
+    FacetBy::new().field(Company::fields().company_name);
+}
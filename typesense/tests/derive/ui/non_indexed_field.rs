@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+use typesense::{Typesense, models::Filter, prelude::*};
+#[derive(Typesense, Serialize, Deserialize)]
+struct Company {
+    #[typesense(index = false)]
+    internal_id: String,
+}
+
+fn main() {
+    Filter::field(Company::fields().internal_id);
+}
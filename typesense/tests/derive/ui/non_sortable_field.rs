@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+use typesense::{Typesense, models::SortField, prelude::*};
+#[derive(Typesense, Serialize, Deserialize)]
+struct Company {
+    company_name: String,
+    #[typesense(sort = false)]
+    num_employees: i32,
+    tags: Vec<i32>,
+}
+
+fn main() {
+    SortField::asc(Company::fields().company_name);
+    SortField::asc(Company::fields().num_employees);
+    SortField::asc(Company::fields().tags);
+}
@@ -51,6 +51,10 @@ pub struct GetDocumentParams<'p> {
     pub collection_name: Cow<'p, str>,
     /// The Document ID
     pub document_id: Cow<'p, str>,
+    /// List of fields from the document to include in the response.
+    pub include_fields: Option<Cow<'p, str>>,
+    /// List of fields from the document to exclude from the response.
+    pub exclude_fields: Option<Cow<'p, str>>,
 }
 
 /// struct for passing parameters to the method [`import_documents`]
@@ -252,6 +256,7 @@ pub struct UpdateDocumentsParams<'p, B> {
     /// The document fields to be updated
     pub body: B,
     pub filter_by: Option<Cow<'p, str>>,
+    pub dirty_values: Option<models::DirtyValues>,
 }
 
 /// struct for typed errors of method [`delete_document`]
@@ -554,6 +559,12 @@ pub async fn get_document(
     );
     let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
 
+    if let Some(ref param_value) = params.include_fields {
+        req_builder = req_builder.query(&[("include_fields", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = params.exclude_fields {
+        req_builder = req_builder.query(&[("exclude_fields", &param_value.to_string())]);
+    }
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
@@ -1390,6 +1401,9 @@ pub async fn update_documents<B: Serialize>(
     if let Some(ref param_value) = params.filter_by {
         req_builder = req_builder.query(&[("filter_by", &param_value.to_string())]);
     }
+    if let Some(ref param_value) = params.dirty_values {
+        req_builder = req_builder.query(&[("dirty_values", &param_value.to_string())]);
+    }
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
@@ -280,6 +280,33 @@ pub struct MultiSearchCollectionParameters<'a> {
         skip_serializing_if = "Option::is_none"
     )]
     pub rerank_hybrid_matches: Option<bool>,
+    /// Whether to use natural language processing to parse the query.
+    #[serde(rename = "nl_query", skip_serializing_if = "Option::is_none")]
+    pub nl_query: Option<bool>,
+    /// The ID of the natural language model to use.
+    #[serde(rename = "nl_model_id", skip_serializing_if = "Option::is_none")]
+    pub nl_model_id: Option<Cow<'a, str>>,
+    /// Controls the number of similar words that Typesense considers during fuzzy search on filter_by values. Useful for controlling prefix matches like company_name:Acm*.
+    #[serde(
+        rename = "max_filter_by_candidates",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub max_filter_by_candidates: Option<i32>,
+    /// Flag for enabling/disabling the deprecated, old highlight structure in the response. Default: true
+    #[serde(
+        rename = "enable_highlight_v1",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub enable_highlight_v1: Option<bool>,
+    /// List of synonym set names to associate with this search query
+    #[serde(rename = "synonym_sets", skip_serializing_if = "Option::is_none")]
+    pub synonym_sets: Option<Cow<'a, str>>,
+    /// Treat space as typo: search for q=basket ball if q=basketball is not found or vice-versa. Splitting/joining of tokens will only be attempted if the original query produces no results. To always trigger this behavior, set value to `always``. To disable, set value to `off`. Default is `fallback`.
+    #[serde(rename = "split_join_tokens", skip_serializing_if = "Option::is_none")]
+    pub split_join_tokens: Option<Cow<'a, str>>,
+    /// Control the number of words that Typesense considers for typo and prefix searching.
+    #[serde(rename = "max_candidates", skip_serializing_if = "Option::is_none")]
+    pub max_candidates: Option<i32>,
 }
 
 impl<'a> MultiSearchCollectionParameters<'a> {
@@ -353,6 +380,13 @@ impl<'a> MultiSearchCollectionParameters<'a> {
             collection: None,
             x_typesense_api_key: None,
             rerank_hybrid_matches: None,
+            nl_query: None,
+            nl_model_id: None,
+            max_filter_by_candidates: None,
+            enable_highlight_v1: None,
+            synonym_sets: None,
+            split_join_tokens: None,
+            max_candidates: None,
         }
     }
 }
@@ -16,10 +16,15 @@ use serde::{Deserialize, Serialize};
 pub struct UpdateDocumentsParameters<'a> {
     #[serde(rename = "filter_by", skip_serializing_if = "Option::is_none")]
     pub filter_by: Option<Cow<'a, str>>,
+    #[serde(rename = "dirty_values", skip_serializing_if = "Option::is_none")]
+    pub dirty_values: Option<models::DirtyValues>,
 }
 
 impl<'a> UpdateDocumentsParameters<'a> {
     pub fn new() -> Self {
-        Self { filter_by: None }
+        Self {
+            filter_by: None,
+            dirty_values: None,
+        }
     }
 }
@@ -384,3 +384,85 @@ pub(crate) fn process_field(
         Ok((Some(build_regular_field(field, &field_attrs)), None))
     }
 }
+
+/// Returns the collection that a field points to with `#[typesense(reference = "...")]`, i.e.
+/// the part of the reference before the first `.`.
+pub(crate) fn referenced_collection(field: &Field) -> syn::Result<Option<String>> {
+    let field_attrs = extract_field_attrs(field)?;
+    if field_attrs.skip && !field_attrs.flatten {
+        return Ok(None);
+    }
+    Ok(field_attrs
+        .reference
+        .map(|reference| match reference.split_once('.') {
+            Some((collection, _)) => collection.to_owned(),
+            None => reference,
+        }))
+}
+
+/// Builds the typed accessor of a single struct field for the `Fields` struct.
+/// Returns the field declaration and its initializer, which use a `prefix: &str` in scope.
+pub(crate) fn process_field_accessor(
+    field: &Field,
+) -> syn::Result<Option<(proc_macro2::TokenStream, proc_macro2::TokenStream)>> {
+    let field_attrs = extract_field_attrs(field)?;
+    let ident = field.ident.as_ref().unwrap();
+    let vis = &field.vis;
+    let name = field_attrs
+        .rename
+        .clone()
+        .unwrap_or_else(|| strip_raw_prefix(&ident.to_string()));
+
+    if field_attrs.flatten {
+        // The object field itself can't be filtered or sorted on, so only the nested
+        // fields are exposed.
+        let inner_type = get_inner_type(&field.ty);
+        return Ok(Some((
+            quote! {
+                #vis #ident: <#inner_type as ::typesense::prelude::DocumentFields>::Fields
+            },
+            quote! {
+                #ident: <#inner_type as ::typesense::prelude::DocumentFields>::prefixed_fields(
+                    &format!("{}{}.", prefix, #name)
+                )
+            },
+        )));
+    }
+    if field_attrs.skip {
+        return Ok(None);
+    }
+
+    let ty = ty_inner_type(&field.ty, "Option").unwrap_or(&field.ty);
+    let is_vec = ty_inner_type(ty, "Vec").is_some();
+
+    let flag = |value: bool| {
+        if value {
+            quote!(::typesense::models::Enabled)
+        } else {
+            quote!(::typesense::models::Disabled)
+        }
+    };
+    let facet = flag(field_attrs.facet.unwrap_or(false));
+    let index = flag(field_attrs.index.unwrap_or(true));
+    // Without an explicit `sort`, `is_sortable_by_default` decides on the declared type, or
+    // the Rust type does through `SortableByDefault`. Arrays are never sortable.
+    let sort = match (field_attrs.sort, &field_attrs.type_override) {
+        (Some(sort), _) => flag(sort),
+        (None, _) if is_vec => flag(false),
+        (None, Some(r#type)) => quote! {
+            ::typesense::models::DefaultSort<
+                ::typesense::models::DeclaredType<{ ::typesense::models::is_sortable_by_default(#r#type) }>
+            >
+        },
+        (None, None) => quote!(::typesense::models::DefaultSort<#ty>),
+    };
+
+    Ok(Some((
+        quote! {
+            #vis #ident: ::typesense::models::FieldRef<#facet, #sort, #index>
+        },
+        quote! {
+            #ident: ::typesense::models::FieldRef::prefixed(prefix, #name)
+        },
+    )))
+}
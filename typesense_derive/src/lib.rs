@@ -1,7 +1,9 @@
 mod field_attributes;
 mod helpers;
 
-use field_attributes::{extract_field_attrs, process_field};
+use field_attributes::{
+    extract_field_attrs, process_field, process_field_accessor, referenced_collection,
+};
 use helpers::*;
 
 use proc_macro::TokenStream;
@@ -135,17 +137,74 @@ fn impl_typesense_collection(item: ItemStruct) -> syn::Result<TokenStream> {
         })
     });
 
+    let mut referenced_collections = Vec::new();
+    for field in &fields {
+        if let Some(collection) = referenced_collection(field)?
+            && !referenced_collections.contains(&collection)
+        {
+            referenced_collections.push(collection);
+        }
+    }
+
     let name_partial = Ident::new(&(ident.to_string() + "Partial"), ident.span());
 
+    // Create Fields struct with typed field references
+    let mut accessor_fields = Vec::new();
+    let mut accessor_inits = Vec::new();
+    for field in &fields {
+        if let Some((decl, init)) = process_field_accessor(field)? {
+            accessor_fields.push(decl);
+            accessor_inits.push(init);
+        }
+    }
+    // Generic field types may appear in `DefaultSort<T>`, so a generic document gets a
+    // generic `Fields` struct that carries its parameters.
+    let document_marker = if generics.params.is_empty() {
+        (
+            proc_macro2::TokenStream::new(),
+            proc_macro2::TokenStream::new(),
+        )
+    } else {
+        (
+            quote! {
+                #[doc(hidden)]
+                _document: ::std::marker::PhantomData<fn() -> #ident #ty_generics>,
+            },
+            quote! { _document: ::std::marker::PhantomData, },
+        )
+    };
+    let (marker_field, marker_init) = document_marker;
+
+    let name_fields = Ident::new(&(ident.to_string() + "Fields"), ident.span());
+
     let generated_code = quote! {
-        #[derive(Default, ::serde::Serialize)]
+        #[derive(Default, ::serde::Serialize, ::serde::Deserialize)]
         #vis struct #name_partial {
             #(#optional_fields)*
         }
         impl ::typesense::prelude::DocumentPartial for #name_partial {}
 
+        #[derive(Clone, Debug)]
+        #vis struct #name_fields #impl_generics #where_clause {
+            #(#accessor_fields,)*
+            #marker_field
+        }
+
+        impl #impl_generics ::typesense::prelude::DocumentFields for #ident #ty_generics #where_clause {
+            type Fields = #name_fields #ty_generics;
+
+            fn prefixed_fields(prefix: &str) -> Self::Fields {
+                let _ = prefix;
+                #name_fields {
+                    #(#accessor_inits,)*
+                    #marker_init
+                }
+            }
+        }
+
         impl #impl_generics ::typesense::prelude::Document for #ident #ty_generics #where_clause {
             const COLLECTION_NAME: &str = #collection_name;
+            const REFERENCED_COLLECTIONS: &[&str] = &[#(#referenced_collections),*];
 
             type Partial = #name_partial;
 